@@ -0,0 +1,133 @@
+//adaptativa.rs
+
+//Estrategia adaptativa: antes de cada búsqueda mira los huecos de la memoria y elige entre
+//First-fit, Best-fit y Worst-fit según umbrales configurables. Cada cambio queda en el registro
+//de eventos y al final se informa cuánto tiempo estuvo activa cada una.
+
+use crate::compactacion;
+use crate::estrategias::{self, Decision, EstrategiaAsignacion, MapaMemoria};
+use crate::proceso::Proceso;
+
+/// Estrategia clásica en la que delega la adaptativa
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+enum SubEstrategia {
+    FirstFit,
+    BestFit,
+    WorstFit,
+}
+
+const SUB_ESTRATEGIAS: [SubEstrategia; 3] = [SubEstrategia::FirstFit, SubEstrategia::BestFit, SubEstrategia::WorstFit];
+
+#[derive(Debug, Clone)]
+pub struct EstrategiaAdaptativa {
+    tamanio_astilla: u32,      // Huecos de menos KB que esto se consideran astillas
+    limite_astillas: u32,      // Con esta cantidad de astillas o más se pasa a Worst-fit
+    umbral_fragmentacion: u32, // Con esta fragmentación externa (%) o más se pasa a Best-fit
+    actual: SubEstrategia,
+    tiempo_activa: [u64; 3], // Tiempo que estuvo activa cada sub-estrategia, en el orden de SUB_ESTRATEGIAS
+    cambios: u32,
+    mensajes: Vec<String>,
+}
+
+impl EstrategiaAdaptativa {
+    /// Comienza con First-fit, que es la más barata mientras la memoria está poco fragmentada
+    pub fn nueva(tamanio_astilla: u32, limite_astillas: u32, umbral_fragmentacion: u32) -> EstrategiaAdaptativa {
+        EstrategiaAdaptativa {
+            tamanio_astilla,
+            limite_astillas,
+            umbral_fragmentacion,
+            actual: SubEstrategia::FirstFit,
+            tiempo_activa: [0; 3],
+            cambios: 0,
+            mensajes: Vec::new(),
+        }
+    }
+
+    /// Sub-estrategia que corresponde al estado de los huecos:
+    /// - muchas astillas: Worst-fit, que corta de los huecos grandes y no deja sobrantes diminutos;
+    /// - memoria fragmentada: Best-fit, que preserva los huecos grandes que quedan;
+    /// - memoria casi contigua: First-fit, que encuentra lugar recorriendo menos.
+    fn elegir_sub_estrategia(&self, astillas: u32, fragmentacion: f64) -> SubEstrategia {
+        if self.limite_astillas > 0 && astillas >= self.limite_astillas {
+            SubEstrategia::WorstFit
+        } else if fragmentacion * 100.0 >= self.umbral_fragmentacion as f64 {
+            SubEstrategia::BestFit
+        } else {
+            SubEstrategia::FirstFit
+        }
+    }
+}
+
+impl EstrategiaAsignacion for EstrategiaAdaptativa {
+    fn nombre(&self) -> String {
+        format!(
+            "Adaptativa({}KB-{}-{}%)",
+            self.tamanio_astilla, self.limite_astillas, self.umbral_fragmentacion
+        )
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        let huecos: Vec<u32> = mapa
+            .particiones
+            .iter()
+            .filter(|p| p.esta_libre())
+            .map(|p| p.tamanio)
+            .collect();
+        let astillas = huecos.iter().filter(|&&hueco| hueco < self.tamanio_astilla).count() as u32;
+        let fragmentacion = compactacion::fragmentacion(&huecos);
+
+        let nueva = self.elegir_sub_estrategia(astillas, fragmentacion);
+        if nueva != self.actual {
+            self.mensajes.push(format!(
+                "la estrategia adaptativa pasa de {:?} a {:?} (fragmentación externa {:.2}%, {} huecos de menos de {} KB)",
+                self.actual,
+                nueva,
+                fragmentacion * 100.0,
+                astillas,
+                self.tamanio_astilla
+            ));
+            self.actual = nueva;
+            self.cambios += 1;
+        }
+
+        match self.actual {
+            SubEstrategia::FirstFit => estrategias::first_fit(mapa.particiones, proceso),
+            SubEstrategia::BestFit => estrategias::best_fit(mapa.particiones, proceso),
+            SubEstrategia::WorstFit => estrategias::worst_fit(mapa.particiones, proceso),
+        }
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(EstrategiaAdaptativa::nueva(
+            self.tamanio_astilla,
+            self.limite_astillas,
+            self.umbral_fragmentacion,
+        ))
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        let actual = SUB_ESTRATEGIAS.iter().position(|&s| s == self.actual).unwrap_or(0);
+        self.tiempo_activa[actual] += duracion;
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.mensajes)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = vec![format!(
+            "Estrategia adaptativa: {} cambios de sub-estrategia.",
+            self.cambios
+        )];
+        for (sub_estrategia, tiempo) in SUB_ESTRATEGIAS.iter().zip(self.tiempo_activa.iter()) {
+            resumen.push(format!(
+                "Estrategia adaptativa: {:?} estuvo activa {} unidades de tiempo ({:.2}%).",
+                sub_estrategia,
+                tiempo,
+                if tiempo_total == 0 { 0.0 } else { *tiempo as f64 * 100.0 / tiempo_total as f64 }
+            ));
+        }
+        resumen
+    }
+}
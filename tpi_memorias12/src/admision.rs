@@ -0,0 +1,53 @@
+use crate::proceso::Proceso;
+
+/// Política de admisión: decide en qué orden se intentan asignar los procesos
+/// que esperan memoria y si un proceso puede adelantarse a otro que no entra.
+#[derive(Debug, Clone)]
+pub enum PoliticaAdmision {
+    Fifo,                // Estricto orden de llegada: si el primero no entra, nadie avanza
+    PrimeroQueEntre,     // Se asigna el primer proceso de la cola que entre en memoria
+    MasChicoPrimero,     // Se intenta primero con el proceso que requiere menos memoria
+    SaltoAcotado(u32),   // Como PrimeroQueEntre, pero un proceso puede ser salteado a lo sumo N veces
+    Prioridad(u64),      // Primero el de mayor prioridad; la prioridad mejora en 1 cada N unidades de espera (0: nunca)
+}
+
+impl PoliticaAdmision {
+    /// Devuelve los procesos de la cola de espera en el orden en que deben intentarse.
+    /// La cola de espera está en orden de llegada; `esperando_desde` indica desde cuándo espera cada proceso.
+    pub fn ordenar_candidatos(&self, cola_espera: &[usize], procesos: &[Proceso], esperando_desde: &[u64], tiempo_global: u64) -> Vec<usize> {
+        let mut candidatos = cola_espera.to_vec();
+        // sort_by_key es estable: a igual clave se respeta el orden de llegada
+        match self {
+            PoliticaAdmision::MasChicoPrimero => {
+                candidatos.sort_by_key(|&indice| procesos[indice].get_memoria_requerida());
+            }
+            PoliticaAdmision::Prioridad(_) => {
+                candidatos.sort_by_key(|&indice| {
+                    self.prioridad_efectiva(procesos[indice].get_prioridad(), tiempo_global - esperando_desde[indice])
+                });
+            }
+            _ => {}
+        }
+        candidatos
+    }
+
+    /// Prioridad con la que compite un proceso que lleva `espera` unidades de tiempo esperando memoria
+    pub fn prioridad_efectiva(&self, prioridad: u32, espera: u64) -> u32 {
+        match self {
+            PoliticaAdmision::Prioridad(intervalo) if *intervalo > 0 => {
+                prioridad.saturating_sub((espera / intervalo).min(u32::MAX as u64) as u32)
+            }
+            _ => prioridad,
+        }
+    }
+
+    /// Indica si otros procesos pueden adelantarse a uno que no entró en memoria,
+    /// sabiendo cuántas veces fue salteado hasta ahora.
+    pub fn permite_saltear(&self, veces_salteado: u32) -> bool {
+        match self {
+            PoliticaAdmision::Fifo => false,
+            PoliticaAdmision::PrimeroQueEntre | PoliticaAdmision::MasChicoPrimero | PoliticaAdmision::Prioridad(_) => true,
+            PoliticaAdmision::SaltoAcotado(limite) => veces_salteado < *limite,
+        }
+    }
+}
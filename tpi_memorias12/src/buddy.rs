@@ -0,0 +1,258 @@
+//buddy.rs
+
+//Sistema buddy binario: la memoria se administra en bloques cuyo tamaño es una potencia de 2.
+//Cada pedido se redondea a la potencia de 2 siguiente; si no hay un bloque libre de ese tamaño
+//se divide uno más grande en dos compañeros (buddies), y al liberar un bloque se combina con su
+//compañero mientras éste también esté libre.
+
+use crate::memoria::{ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::{EstadoParticion, Particion};
+use crate::proceso::Proceso;
+use std::collections::BTreeSet;
+
+/// Bloque entregado a un proceso
+struct BloqueAsignado {
+    particion: Particion,
+    memoria_requerida: u32, // KB que pidió el proceso; el resto del bloque es fragmentación interna
+}
+
+pub struct MemoriaBuddy {
+    orden_minimo: u32, // El bloque más chico mide 2^orden_minimo KB
+    raices: Vec<(u32, u32)>, // Dirección y orden de los bloques más grandes, que no tienen compañero
+    libres: Vec<BTreeSet<u32>>, // Direcciones de los bloques libres, por orden
+    asignados: Vec<BloqueAsignado>,
+    tamanio_memoria: u32, // Memoria física total, aunque parte quede fuera del sistema buddy
+    mensajes: Vec<String>, // Divisiones y combinaciones pendientes de registrar como eventos
+    divisiones: u32,
+    combinaciones: u32,
+    desperdicio_acumulado: u64, // KB de fragmentación interna por unidad de tiempo
+    asignado_acumulado: u64, // KB asignados a procesos por unidad de tiempo
+}
+
+impl MemoriaBuddy {
+    /// La memoria se descompone en bloques raíz de potencias de 2 decrecientes (por ejemplo,
+    /// 1000 KB = 512 + 256 + 128 + 64 + 32 + 8). Lo que no llega al bloque mínimo no se usa.
+    pub fn nueva(tamanio_memoria: u32, orden_minimo: u32) -> MemoriaBuddy {
+        let mut raices = Vec::new();
+        let mut libres = vec![BTreeSet::new(); 32];
+        let mut direccion = 0;
+        for orden in (orden_minimo..32).rev() {
+            if tamanio_memoria - direccion >= 1 << orden {
+                raices.push((direccion, orden));
+                libres[orden as usize].insert(direccion);
+                direccion += 1 << orden;
+            }
+        }
+
+        MemoriaBuddy {
+            orden_minimo,
+            raices,
+            libres,
+            asignados: Vec::new(),
+            tamanio_memoria,
+            mensajes: Vec::new(),
+            divisiones: 0,
+            combinaciones: 0,
+            desperdicio_acumulado: 0,
+            asignado_acumulado: 0,
+        }
+    }
+
+    /// Orden del bloque que necesita un pedido de `memoria_requerida` KB
+    fn orden_necesario(&self, memoria_requerida: u32) -> u32 {
+        memoria_requerida.max(1).next_power_of_two().trailing_zeros().max(self.orden_minimo)
+    }
+
+    /// Orden del bloque raíz que contiene la dirección; un bloque de ese orden no tiene compañero
+    fn orden_raiz(&self, direccion: u32) -> u32 {
+        self.raices
+            .iter()
+            .find(|&&(comienzo, orden)| direccion >= comienzo && direccion < comienzo + (1 << orden))
+            .map(|&(_, orden)| orden)
+            .unwrap_or(self.orden_minimo)
+    }
+
+    /// Fragmentación interna actual, en KB
+    fn fragmentacion_interna(&self) -> u32 {
+        self.asignados.iter().map(|b| b.particion.tamanio - b.memoria_requerida).sum()
+    }
+}
+
+impl ModeloMemoria for MemoriaBuddy {
+    /// Se toma el bloque libre más chico que alcanza (el de menor dirección entre los de ese
+    /// tamaño) y se divide a la mitad hasta llegar al orden necesario.
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let orden_necesario = self.orden_necesario(proceso.get_memoria_requerida());
+        let mut orden = (orden_necesario..32).find(|&orden| !self.libres[orden as usize].is_empty())?;
+        let direccion = self.libres[orden as usize].pop_first()?;
+
+        while orden > orden_necesario {
+            orden -= 1;
+            let companero = direccion + (1 << orden);
+            self.libres[orden as usize].insert(companero);
+            self.divisiones += 1;
+            self.mensajes.push(format!(
+                "se dividió el bloque de {} KB de la dirección {} en dos compañeros de {} KB (direcciones {} y {})",
+                1u32 << (orden + 1),
+                direccion,
+                1u32 << orden,
+                direccion,
+                companero
+            ));
+        }
+
+        let mut particion = Particion::nueva(direccion, 1 << orden);
+        particion.ocupar(proceso.nombre.clone(), proceso.arribo as u32, tiempo_carga);
+        self.asignados.push(BloqueAsignado {
+            particion,
+            memoria_requerida: proceso.get_memoria_requerida(),
+        });
+
+        Some(Ubicacion {
+            direccion,
+            tamanio: 1 << orden,
+        })
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for bloque in self.asignados.iter_mut() {
+            if bloque.particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                bloque.particion.estado = estado.clone();
+            }
+        }
+    }
+
+    /// Mientras el nuevo tamaño entre en el bloque actual cambia en el lugar (sólo varía la
+    /// fragmentación interna); si no, se asigna un bloque más grande y se libera el anterior.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let Some(actual) = self
+            .asignados
+            .iter_mut()
+            .find(|b| b.particion.id_proceso.as_deref() == Some(proceso.get_nombre()))
+        else {
+            return Redimension::SinLugar;
+        };
+        if proceso.get_memoria_requerida() <= actual.particion.tamanio {
+            actual.memoria_requerida = proceso.get_memoria_requerida();
+            return Redimension::EnLugar;
+        }
+
+        // El bloque nuevo se agrega al final de los asignados, así que `liberar` encuentra primero al anterior
+        let estado = actual.particion.estado.clone();
+        let Some(ubicacion) = self.asignar(proceso, 0) else {
+            return Redimension::SinLugar;
+        };
+        self.liberar(proceso.get_nombre());
+        self.cambiar_estado(proceso.get_nombre(), estado);
+        Redimension::Reubicado(ubicacion)
+    }
+
+    /// El bloque liberado se combina con su compañero mientras éste esté libre y entero.
+    fn liberar(&mut self, nombre_proceso: &str) {
+        let Some(posicion) = self
+            .asignados
+            .iter()
+            .position(|b| b.particion.id_proceso.as_deref() == Some(nombre_proceso))
+        else {
+            return;
+        };
+        let bloque = self.asignados.remove(posicion);
+        let mut direccion = bloque.particion.direccion_comienzo;
+        let mut orden = bloque.particion.tamanio.trailing_zeros();
+
+        while orden < self.orden_raiz(direccion) {
+            let companero = direccion ^ (1 << orden);
+            if !self.libres[orden as usize].remove(&companero) {
+                break;
+            }
+            self.combinaciones += 1;
+            self.mensajes.push(format!(
+                "se combinó el bloque de {} KB de la dirección {} con su compañero de la dirección {}, formando un bloque de {} KB en la dirección {}",
+                1u32 << orden,
+                direccion,
+                companero,
+                1u32 << (orden + 1),
+                direccion.min(companero)
+            ));
+            direccion = direccion.min(companero);
+            orden += 1;
+        }
+        self.libres[orden as usize].insert(direccion);
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.raices.iter().map(|&(_, orden)| 1 << orden).sum()
+    }
+
+    /// El bloque más grande es la primera raíz: los bloques raíz no se combinan entre sí
+    fn maximo_asignable(&self) -> u32 {
+        self.raices.first().map_or(0, |&(_, orden)| 1 << orden)
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.asignados.iter().map(|b| b.particion.tamanio).sum()
+    }
+
+    /// Cada bloque libre es un hueco: dos bloques libres vecinos que no son compañeros no se combinan
+    fn huecos(&self) -> Vec<u32> {
+        let mut bloques: Vec<(u32, u32)> = self
+            .libres
+            .iter()
+            .enumerate()
+            .flat_map(|(orden, direcciones)| direcciones.iter().map(move |&d| (d, 1 << orden)))
+            .collect();
+        bloques.sort();
+        bloques.into_iter().map(|(_, tamanio)| tamanio).collect()
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        self.desperdicio_acumulado += self.fragmentacion_interna() as u64 * duracion;
+        self.asignado_acumulado += self.memoria_ocupada() as u64 * duracion;
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.mensajes)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = Vec::new();
+        let orden_maximo = self.raices.first().map(|&(_, orden)| orden).unwrap_or(self.orden_minimo);
+        let sin_usar = self.tamanio_memoria - self.tamanio_total();
+        resumen.push(format!(
+            "Sistema buddy: bloques de {} a {} KB, {} KB administrados en {} bloques raíz{}.",
+            1u32 << self.orden_minimo,
+            1u32 << orden_maximo,
+            self.tamanio_total(),
+            self.raices.len(),
+            if sin_usar > 0 {
+                format!(" ({} KB sin usar por ser menores que el bloque mínimo)", sin_usar)
+            } else {
+                String::new()
+            }
+        ));
+        resumen.push(format!(
+            "Divisiones de bloques: {}. Combinaciones de compañeros: {}. Fragmentación interna al finalizar: {} KB.",
+            self.divisiones,
+            self.combinaciones,
+            self.fragmentacion_interna()
+        ));
+
+        // Promedios ponderados por tiempo sobre toda la simulación
+        let desperdicio_medio = if tiempo_total > 0 {
+            self.desperdicio_acumulado as f64 / tiempo_total as f64
+        } else {
+            0.0
+        };
+        let porcentaje = if self.asignado_acumulado > 0 {
+            self.desperdicio_acumulado as f64 * 100.0 / self.asignado_acumulado as f64
+        } else {
+            0.0
+        };
+        resumen.push(format!(
+            "Fragmentación interna media por redondeo: {:.2} KB ({:.2}% de la memoria asignada a procesos).",
+            desperdicio_medio,
+            porcentaje
+        ));
+        resumen
+    }
+}
@@ -0,0 +1,47 @@
+use crate::particion::Particion;
+
+/// Define cuándo se compacta la memoria.
+#[derive(Debug, Clone)]
+pub enum Compactacion {
+    Deshabilitada,
+    AlFallarAsignacion,        // Cuando un proceso no entra pero la memoria libre total alcanza
+    UmbralFragmentacion(u32),  // Cuando la fragmentación (en %) supera el umbral tras una liberación
+    Periodica(u64),            // Cada N unidades de tiempo
+}
+
+/// Función para compactar la memoria
+/// Desliza los bloques ocupados hacia la dirección 0, conservando su orden, y deja
+/// toda la memoria libre en un único hueco al final. Devuelve los KB que se movieron.
+pub fn compactar(particiones: &mut Vec<Particion>) -> u32 {
+    let tamanio_memoria: u32 = particiones.iter().map(|p| p.tamanio).sum();
+    let mut kb_movidos = 0;
+    let mut direccion_libre = 0;
+
+    particiones.retain(|p| !p.esta_libre());
+    for particion in particiones.iter_mut() {
+        if particion.direccion_comienzo != direccion_libre {
+            kb_movidos += particion.tamanio;
+            particion.direccion_comienzo = direccion_libre;
+        }
+        direccion_libre += particion.tamanio;
+    }
+    if direccion_libre < tamanio_memoria {
+        particiones.push(Particion::nueva(direccion_libre, tamanio_memoria - direccion_libre));
+    }
+
+    kb_movidos
+}
+
+/// Función para calcular la fragmentación de la memoria libre
+/// Recibe el tamaño de cada hueco y devuelve 1 - hueco_mas_grande / memoria_libre_total
+/// (0 si no hay memoria libre).
+pub fn fragmentacion(huecos: &[u32]) -> f64 {
+    let libre_total: u32 = huecos.iter().sum();
+    let hueco_mas_grande = huecos.iter().copied().max().unwrap_or(0);
+
+    if libre_total == 0 {
+        0.0
+    } else {
+        1.0 - hueco_mas_grande as f64 / libre_total as f64
+    }
+}
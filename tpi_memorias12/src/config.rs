@@ -1,12 +1,31 @@
-use crate::estrategias::EstrategiaAsignacion;  // Asegúrate de que está importando correctamente
+use crate::estrategias::{BestFit, EstrategiaAsignacion, RegistroEstrategias};
+use crate::admision::PoliticaAdmision;
+use crate::compactacion::Compactacion;
+use crate::memoria::ModoMemoria;
+use crate::swapping::PoliticaSwapping;
+use crate::planificador::PlanificacionCpu;
+use crate::insatisfacibles::PoliticaInsatisfacibles;
+use crate::slab::{ORDEN_SLAB_MINIMO, TIPOS_OBJETO};
 use std::io::{self, Write};
 
 pub struct Config {
-    pub estrategia: EstrategiaAsignacion,  // Usamos la estrategia de asignación definida en estrategias.rs
+    pub estrategia: Box<dyn EstrategiaAsignacion>,  // Una de las estrategias registradas en estrategias.rs
     pub tamanio_memoria: u32,
+    pub modo_memoria: ModoMemoria,
     pub tiempo_seleccion: u32,
     pub tiempo_carga: u32,
     pub tiempo_liberacion: u32,
+    pub politica_admision: PoliticaAdmision,
+    pub desalojo_por_prioridad: bool, // Un proceso de mayor prioridad puede llevar al swap a los de menor
+    pub compactacion: Compactacion,
+    pub tiempo_reubicacion_kb: u32,
+    pub swapping: PoliticaSwapping,
+    pub tamanio_swap: u32,            // Capacidad del área de swap, en KB
+    pub tasa_transferencia_swap: u32, // KB que se copian por unidad de tiempo entre memoria y swap
+    pub residencia_minima_swap: u64,  // CPU que un proceso usa desde que se carga antes de poder llevarse al swap
+    pub planificacion: PlanificacionCpu,
+    pub dispositivos_es: u32, // Dispositivos de E/S, cada uno con su propia cola
+    pub insatisfacibles: PoliticaInsatisfacibles,
 }
 
 impl Config {
@@ -21,18 +40,72 @@ impl Config {
 
         // Obtener los valores para la configuración
         let tamanio_memoria = Config::set_tamanio_memoria();
-        let estrategia = Config::set_estrategia();  // Selecciona la estrategia
+        let modo_memoria = Config::set_modo_memoria(tamanio_memoria);
+        // El sistema buddy siempre usa el bloque libre más chico que alcanza, con paginación
+        // cualquier marco libre sirve, el ajuste segregado busca en sus propias listas y el slab en sus caches
+        let estrategia = match modo_memoria {
+            ModoMemoria::Buddy { .. }
+            | ModoMemoria::Paginacion { .. }
+            | ModoMemoria::Segregada { .. }
+            | ModoMemoria::Slab { .. } => Box::new(BestFit),
+            _ => Config::set_estrategia(),  // Selecciona la estrategia
+        };
         let tiempo_seleccion = Config::tiempo_seleccion();
         let tiempo_carga = Config::tiempo_carga_promedio();
         let tiempo_liberacion = Config::tiempo_liberacion();
+        let politica_admision = Config::set_politica_admision();
+        // La compactación sólo tiene sentido con particiones dinámicas (también con segmentación)
+        let compactacion = match modo_memoria {
+            ModoMemoria::ParticionesDinamicas | ModoMemoria::Segmentacion => Config::set_compactacion(),
+            _ => Compactacion::Deshabilitada,
+        };
+        let tiempo_reubicacion_kb = match compactacion {
+            Compactacion::Deshabilitada => 0,
+            _ => Config::tiempo_reubicacion_kb(),
+        };
+        let swapping = Config::set_swapping();
+        let (tamanio_swap, tasa_transferencia_swap, residencia_minima_swap) = match swapping {
+            PoliticaSwapping::Deshabilitado => (0, 1, 0),
+            _ => (
+                Config::pedir_numero("Ingrese el tamaño del área de swap (en KB): ", 1),
+                Config::pedir_numero("Ingrese la tasa de transferencia del swap (en KB por unidad de tiempo): ", 1),
+                Config::pedir_numero(
+                    "Ingrese el tiempo mínimo de CPU que un proceso usa tras cargarse antes de poder llevarse al swap: ",
+                    0,
+                ) as u64,
+            ),
+        };
+        // Sólo se desaloja por prioridad si la admisión la tiene en cuenta y hay adónde llevar a las víctimas
+        let desalojo_por_prioridad = match (&politica_admision, &swapping) {
+            (PoliticaAdmision::Prioridad(_), PoliticaSwapping::MasAntiguo | PoliticaSwapping::MasGrande | PoliticaSwapping::MenorTiempoRestante) => {
+                Config::set_desalojo_por_prioridad()
+            }
+            _ => false,
+        };
+
+        let planificacion = Config::set_planificacion();
+        let dispositivos_es = Config::set_dispositivos_es();
+        let insatisfacibles = Config::set_insatisfacibles();
 
         // Retorna una nueva configuración con los valores obtenidos
         Config {
             estrategia,
             tamanio_memoria,
+            modo_memoria,
             tiempo_seleccion,
             tiempo_carga,
             tiempo_liberacion,
+            politica_admision,
+            desalojo_por_prioridad,
+            compactacion,
+            tiempo_reubicacion_kb,
+            swapping,
+            tamanio_swap,
+            tasa_transferencia_swap,
+            residencia_minima_swap,
+            planificacion,
+            dispositivos_es,
+            insatisfacibles,
         }
     }
 
@@ -43,7 +116,7 @@ impl Config {
             println!("Este valor define cuánta memoria está disponible en KB para los procesos.");
             println!("Recuerde que el sistema operativo ya utiliza parte de la memoria, así que ingrese");
             println!("un valor que represente la memoria disponible para los usuarios.");
-            println!("<<advertencia: No debe crear una memoria menor al proceso mas grande que tiene>>");
+            println!("<<advertencia: un proceso más grande que la memoria nunca podrá cargarse; el punto 12 define qué hacer con él>>");
             print!("Ingrese el tamaño de la memoria física disponible (en KB): ");
             io::stdout().flush().unwrap();
 
@@ -57,67 +130,468 @@ impl Config {
         }
     }
 
-    /// Función para seleccionar la estrategia de asignación
-    fn set_estrategia() -> EstrategiaAsignacion {
+    /// Función para seleccionar el modelo de memoria
+    fn set_modo_memoria(tamanio_memoria: u32) -> ModoMemoria {
+        loop {
+            println!("\n2. Selección del Modelo de Memoria:");
+            println!("1) Particiones dinámicas: cada proceso recibe un bloque del tamaño exacto que pide.");
+            println!("2) Particiones fijas: la memoria se divide de antemano en particiones que no cambian;");
+            println!("   cada proceso ocupa una partición entera y el sobrante es fragmentación interna.");
+            println!("3) Sistema buddy: los pedidos se redondean a potencias de 2 y los bloques se dividen");
+            println!("   y se combinan con su compañero (buddy). No usa estrategia de asignación.");
+            println!("4) Paginación: la memoria se divide en marcos y cada página de un proceso va a cualquier");
+            println!("   marco libre, sin necesidad de que sean contiguos. No usa estrategia de asignación.");
+            println!("5) Segmentación: los segmentos de código, datos y pila de cada proceso se ubican por separado");
+            println!("   con la estrategia elegida; el proceso se carga sólo si entran todos.");
+            println!("6) Ajuste segregado: los bloques libres se guardan en listas por tamaño (listas rápidas para");
+            println!("   tamaños frecuentes, clases por potencias de 2 y una lista general). No usa estrategia de asignación.");
+            println!("7) Slab: los procesos piden objetos del kernel de tipos fijos, que se ubican en caches de slabs");
+            println!("   tomados del sistema buddy. No usa estrategia de asignación.");
+            print!("Seleccione una opción (1-7): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return ModoMemoria::ParticionesDinamicas,
+                "2" => return ModoMemoria::ParticionesFijas(Config::set_particiones_fijas(tamanio_memoria)),
+                "3" => return ModoMemoria::Buddy { orden_minimo: Config::set_orden_minimo(tamanio_memoria) },
+                "4" => return ModoMemoria::Paginacion { tamanio_pagina: Config::set_tamanio_pagina(tamanio_memoria) },
+                "5" => return ModoMemoria::Segmentacion,
+                "6" => return Config::set_segregada(tamanio_memoria),
+                "7" => return Config::set_slab(tamanio_memoria),
+                _ => println!("Opción no válida. Por favor ingrese un número entre 1 y 7."),
+            }
+        }
+    }
+
+    /// Función para definir la tabla de particiones fijas
+    fn set_particiones_fijas(tamanio_memoria: u32) -> Vec<u32> {
+        loop {
+            println!("\nTabla de Particiones Fijas:");
+            println!("1) Todas las particiones del mismo tamaño.");
+            println!("2) Lista de tamaños definida por el usuario.");
+            print!("Seleccione una opción (1-2): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            let tamanios = match opcion.trim() {
+                "1" => {
+                    let tamanio = Config::pedir_numero("Ingrese el tamaño de cada partición (en KB): ", 1);
+                    vec![tamanio; (tamanio_memoria / tamanio) as usize]
+                }
+                "2" => {
+                    print!("Ingrese los tamaños de las particiones en KB, separados por comas (ej: 100,200,300): ");
+                    io::stdout().flush().unwrap();
+                    let mut lista = String::new();
+                    io::stdin().read_line(&mut lista).expect("Error al leer la lista");
+                    match lista.trim().split(',').map(|t| t.trim().parse::<u32>()).collect::<Result<Vec<u32>, _>>() {
+                        Ok(tamanios) if tamanios.iter().all(|&t| t > 0) => tamanios,
+                        _ => {
+                            println!("La lista debe contener números mayores que 0 separados por comas.");
+                            continue;
+                        }
+                    }
+                }
+                _ => {
+                    println!("Opción no válida. Por favor ingrese 1 o 2.");
+                    continue;
+                }
+            };
+
+            let total: u32 = tamanios.iter().sum();
+            if tamanios.is_empty() || total > tamanio_memoria {
+                println!("Las particiones deben sumar entre 1 y {} KB (suman {} KB).", tamanio_memoria, total);
+                continue;
+            }
+            if total < tamanio_memoria {
+                println!("Quedan {} KB sin particionar que no se usarán.", tamanio_memoria - total);
+            }
+            return tamanios;
+        }
+    }
+
+    /// Función para pedir el orden mínimo de bloque del sistema buddy
+    fn set_orden_minimo(tamanio_memoria: u32) -> u32 {
+        loop {
+            println!("\nOrden Mínimo de Bloque:");
+            println!("El bloque más chico del sistema buddy mide 2^orden KB; los bloques no se dividen por debajo de ese tamaño.");
+            let orden = Config::pedir_numero("Ingrese el orden mínimo de bloque (ej: 4 para bloques de 16 KB): ", 0);
+            if orden < 32 && 1u32 << orden <= tamanio_memoria {
+                return orden;
+            }
+            println!("El bloque mínimo no puede superar el tamaño de la memoria ({} KB).", tamanio_memoria);
+        }
+    }
+
+    /// Función para pedir el tamaño de página (y de marco) de la paginación
+    fn set_tamanio_pagina(tamanio_memoria: u32) -> u32 {
+        loop {
+            println!("\nTamaño de Página:");
+            println!("Las páginas de los procesos y los marcos de la memoria tienen este mismo tamaño.");
+            let tamanio = Config::pedir_numero("Ingrese el tamaño de página (en KB): ", 1);
+            if tamanio <= tamanio_memoria {
+                return tamanio;
+            }
+            println!("La página no puede superar el tamaño de la memoria ({} KB).", tamanio_memoria);
+        }
+    }
+
+    /// Función para definir las listas del ajuste segregado
+    fn set_segregada(tamanio_memoria: u32) -> ModoMemoria {
+        println!("\nListas del Ajuste Segregado:");
+        println!("Los bloques de un tamaño frecuente que se liberan quedan en su lista rápida, sin combinarse,");
+        println!("para entregarlos enseguida al próximo pedido del mismo tamaño.");
+        let tamanios_rapidos = loop {
+            print!("Ingrese los tamaños frecuentes en KB, separados por comas (ej: 100,200), o deje vacío para no usar listas rápidas: ");
+            io::stdout().flush().unwrap();
+            let mut lista = String::new();
+            io::stdin().read_line(&mut lista).expect("Error al leer la lista");
+            if lista.trim().is_empty() {
+                break Vec::new();
+            }
+            match lista.trim().split(',').map(|t| t.trim().parse::<u32>()).collect::<Result<Vec<u32>, _>>() {
+                Ok(mut tamanios) if tamanios.iter().all(|&t| t > 0 && t <= tamanio_memoria) => {
+                    tamanios.sort();
+                    tamanios.dedup();
+                    break tamanios;
+                }
+                _ => println!("La lista debe contener números entre 1 y {} separados por comas.", tamanio_memoria),
+            }
+        };
+        println!("Los demás bloques se agrupan en clases por potencias de 2; los más grandes van a una lista general.");
+        let limite_general = loop {
+            let limite = Config::pedir_numero("Ingrese el tamaño a partir del cual los bloques van a la lista general (en KB): ", 1);
+            if limite <= tamanio_memoria {
+                break limite;
+            }
+            println!("El límite no puede superar el tamaño de la memoria ({} KB).", tamanio_memoria);
+        };
+        ModoMemoria::Segregada { tamanios_rapidos, limite_general }
+    }
+
+    /// Función para definir el tamaño de los slabs y si se recuperan los vacíos
+    fn set_slab(tamanio_memoria: u32) -> ModoMemoria {
+        println!("\nCaches de Slabs:");
+        println!("Cada slab es un bloque del sistema buddy de 2^orden KB dividido en objetos de un mismo tipo");
+        println!("({}).", TIPOS_OBJETO.map(|t| format!("{} de {} KB", t.nombre(), t.tamanio())).join(", "));
+        let orden_slab = loop {
+            let orden = Config::pedir_numero(
+                &format!("Ingrese el orden de los slabs (ej: 5 para slabs de 32 KB, como mínimo {}): ", ORDEN_SLAB_MINIMO),
+                ORDEN_SLAB_MINIMO,
+            );
+            if orden < 32 && 1u32 << orden <= tamanio_memoria {
+                break orden;
+            }
+            println!("El slab no puede superar el tamaño de la memoria ({} KB).", tamanio_memoria);
+        };
+        let recuperar_vacios = loop {
+            print!("¿Devolver los slabs vacíos al sistema buddy cuando otra cache necesite memoria? (s/n): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim().to_lowercase().as_str() {
+                "s" => break true,
+                "n" => break false,
+                _ => println!("Por favor ingrese 's' o 'n'."),
+            }
+        };
+        ModoMemoria::Slab { orden_slab, recuperar_vacios }
+    }
+
+    /// Nombre de la estrategia para el archivo de resultados
+    pub fn nombre_estrategia(&self) -> String {
+        match self.modo_memoria {
+            ModoMemoria::Buddy { .. } => "Buddy".to_string(),
+            ModoMemoria::Paginacion { .. } => "Paginacion".to_string(),
+            ModoMemoria::Segregada { .. } => "Segregada".to_string(),
+            ModoMemoria::Slab { .. } => "Slab".to_string(),
+            _ => self.estrategia.nombre(),
+        }
+    }
+
+    /// Función para seleccionar la estrategia de asignación entre las registradas
+    fn set_estrategia() -> Box<dyn EstrategiaAsignacion> {
+        let registro = RegistroEstrategias::nuevo();
+        let estrategias = registro.estrategias();
         loop {
-            println!("\n2. Selección de la Estrategia de Asignación:");
+            println!("\n3. Selección de la Estrategia de Asignación:");
             println!("Escoja la estrategia que se utilizará para asignar los procesos a la memoria.");
+            println!("Con particiones fijas, la estrategia elige entre las particiones libres que alcanzan;");
+            println!("con segmentación, se aplica a cada segmento por separado.");
             println!("Estas son las opciones disponibles:");
-            println!("1) First-fit (Primer ajuste): Asigna el primer espacio libre que sea suficiente.");
-            println!("2) Best-fit (Mejor ajuste): Busca la partición más pequeña posible que sea suficiente.");
-            println!("3) Next-fit (Siguiente ajuste): Busca el siguiente espacio libre desde el último utilizado.");
-            println!("4) Worst-fit (Peor ajuste): Busca la partición más grande disponible.");
-            print!("Seleccione una opción (1-4): ");
+            for (numero, estrategia) in estrategias.iter().enumerate() {
+                println!("{}) {}", numero + 1, estrategia.descripcion);
+            }
+            print!("Seleccione una opción (1-{}): ", estrategias.len());
             io::stdout().flush().unwrap();
 
             let mut opcion = String::new();
             io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
 
-            match opcion.trim() {
-                "1" => return EstrategiaAsignacion::FirstFit,
-                "2" => return EstrategiaAsignacion::BestFit,
-                "3" => return EstrategiaAsignacion::NextFit,
-                "4" => return EstrategiaAsignacion::WorstFit,
-                _ => println!("Opción no válida. Por favor ingrese 1, 2, 3 o 4."),
+            match opcion.trim().parse::<usize>() {
+                Ok(numero) if (1..=estrategias.len()).contains(&numero) => {
+                    return (estrategias[numero - 1].crear)(Config::pedir_numero);
+                }
+                _ => println!("Opción no válida. Por favor ingrese un número del 1 al {}.", estrategias.len()),
             }
         }
     }
 
     /// Función para pedir el tiempo de selección de partición
     fn tiempo_seleccion() -> u32 {
-        println!("\n3. Tiempo de Selección de Partición:");
+        println!("\n4. Tiempo de Selección de Partición:");
         println!("Este valor representa el tiempo que toma seleccionar la partición de memoria");
-        println!("para un proceso (en milisegundos). Un valor más alto simulará un sistema más lento.");
+        println!("para un proceso (en unidades de tiempo). La partición queda reservada desde la selección,");
+        println!("pero el proceso recién comienza a ejecutarse cuando terminan la selección y la carga.");
         Config::pedir_tiempo("selección de partición")
     }
 
     /// Función para pedir el tiempo de carga promedio
     fn tiempo_carga_promedio() -> u32 {
-        println!("\n4. Tiempo de Carga Promedio:");
+        println!("\n5. Tiempo de Carga Promedio:");
         println!("Este es el tiempo que toma cargar un proceso desde la memoria secundaria a la principal.");
-        println!("Ingrese un valor en milisegundos. Un valor más alto simulará un proceso de carga más lento.");
+        println!("Ingrese un valor en unidades de tiempo. Se suma al tiempo de selección antes de que el proceso se ejecute.");
         Config::pedir_tiempo("carga promedio")
     }
 
     /// Función para pedir el tiempo de liberación de partición
     fn tiempo_liberacion() -> u32 {
-        println!("\n5. Tiempo de Liberación de Partición:");
+        println!("\n6. Tiempo de Liberación de Partición:");
         println!("Este valor representa el tiempo necesario para liberar una partición de memoria");
-        println!("cuando un proceso termina. Ingrese un valor en milisegundos.");
+        println!("cuando un proceso termina. La partición sigue ocupada durante este tiempo.");
+        println!("Ingrese un valor en unidades de tiempo.");
         Config::pedir_tiempo("liberación de partición")
     }
 
+    /// Función para seleccionar la política de admisión de la cola de espera
+    fn set_politica_admision() -> PoliticaAdmision {
+        loop {
+            println!("\n7. Selección de la Política de Admisión:");
+            println!("Define qué ocurre cuando el proceso que más tiempo lleva esperando no entra en memoria.");
+            println!("1) FIFO estricto: nadie avanza hasta que el primero de la cola entre.");
+            println!("2) Primero que entre: se asigna el primer proceso de la cola que entre en memoria.");
+            println!("3) Más chico primero: se intenta primero con el proceso que requiere menos memoria.");
+            println!("4) Salto acotado: como la opción 2, pero un proceso puede ser salteado un número limitado de veces.");
+            println!("5) Por prioridad: se intenta primero con el proceso de mayor prioridad (0 es la más alta).");
+            print!("Seleccione una opción (1-5): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return PoliticaAdmision::Fifo,
+                "2" => return PoliticaAdmision::PrimeroQueEntre,
+                "3" => return PoliticaAdmision::MasChicoPrimero,
+                "4" => return PoliticaAdmision::SaltoAcotado(Config::pedir_limite_saltos()),
+                "5" => return PoliticaAdmision::Prioridad(Config::pedir_envejecimiento()),
+                _ => println!("Opción no válida. Por favor ingrese 1, 2, 3, 4 o 5."),
+            }
+        }
+    }
+
+    /// Función para pedir cuántas veces puede ser salteado un proceso en espera
+    fn pedir_limite_saltos() -> u32 {
+        Config::pedir_numero("Ingrese cuántas veces puede ser salteado un proceso antes de bloquear la cola: ", 0)
+    }
+
+    /// Función para pedir cada cuánto mejora la prioridad de un proceso que espera memoria
+    fn pedir_envejecimiento() -> u64 {
+        println!("Envejecimiento: un proceso que espera memoria mejora su prioridad en 1 por cada intervalo");
+        println!("de espera, para que los de baja prioridad no esperen para siempre. Con 0 no hay envejecimiento.");
+        Config::pedir_numero("Ingrese el intervalo de envejecimiento (en unidades de tiempo): ", 0) as u64
+    }
+
+    /// Función para seleccionar cuándo se compacta la memoria
+    fn set_compactacion() -> Compactacion {
+        loop {
+            println!("\n8. Compactación de Memoria:");
+            println!("La compactación desliza los procesos hacia la dirección 0 para unir los huecos libres.");
+            println!("Mientras se compacta no se asignan procesos y los procesos en memoria quedan detenidos.");
+            println!("1) Sin compactación.");
+            println!("2) Al fallar una asignación, si la memoria libre total alcanza para el proceso.");
+            println!("3) Cuando la fragmentación supera un umbral, luego de cada liberación.");
+            println!("4) Periódica, cada cierto intervalo de tiempo.");
+            print!("Seleccione una opción (1-4): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return Compactacion::Deshabilitada,
+                "2" => return Compactacion::AlFallarAsignacion,
+                "3" => {
+                    println!("La fragmentación se calcula como 1 - (hueco más grande / memoria libre total).");
+                    return Compactacion::UmbralFragmentacion(Config::pedir_numero(
+                        "Ingrese el umbral de fragmentación (en %, entre 0 y 99): ",
+                        0,
+                    ).min(99));
+                }
+                "4" => {
+                    return Compactacion::Periodica(Config::pedir_numero(
+                        "Ingrese el intervalo entre compactaciones (en unidades de tiempo): ",
+                        1,
+                    ) as u64)
+                }
+                _ => println!("Opción no válida. Por favor ingrese 1, 2, 3 o 4."),
+            }
+        }
+    }
+
+    /// Función para pedir el tiempo de reubicación por KB durante la compactación
+    fn tiempo_reubicacion_kb() -> u32 {
+        println!("\nTiempo de Reubicación:");
+        println!("Es el tiempo que cuesta mover cada KB de un proceso durante la compactación.");
+        Config::pedir_tiempo("reubicación por KB")
+    }
+
+    /// Función para seleccionar la política de swapping
+    fn set_swapping() -> PoliticaSwapping {
+        loop {
+            println!("\n9. Swapping:");
+            println!("Cuando un proceso que espera no entra en memoria, se pueden llevar procesos en ejecución");
+            println!("a un área de swap para hacerle lugar. Vuelven a memoria más tarde y continúan donde quedaron.");
+            println!("Un proceso puede volver al swap sólo después de usar la CPU un tiempo mínimo desde que se cargó");
+            println!("(al menos una unidad), y nunca para hacerle lugar a un proceso que empezó a esperar después de");
+            println!("que él se cargó. Cada vez que un proceso espera memoria, se hace lugar para él una sola vez.");
+            println!("1) Sin swapping.");
+            println!("2) Llevar al swap al proceso que lleva más tiempo en memoria.");
+            println!("3) Llevar al swap al proceso más grande.");
+            println!("4) Llevar al swap al proceso con menos tiempo de ejecución restante.");
+            print!("Seleccione una opción (1-4): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return PoliticaSwapping::Deshabilitado,
+                "2" => return PoliticaSwapping::MasAntiguo,
+                "3" => return PoliticaSwapping::MasGrande,
+                "4" => return PoliticaSwapping::MenorTiempoRestante,
+                _ => println!("Opción no válida. Por favor ingrese 1, 2, 3 o 4."),
+            }
+        }
+    }
+
+    /// Función para preguntar si un proceso de mayor prioridad puede desalojar a los de menor prioridad
+    fn set_desalojo_por_prioridad() -> bool {
+        loop {
+            println!("\nDesalojo por prioridad:");
+            println!("Si se habilita, sólo se llevan al swap procesos de menor prioridad que el que no entra,");
+            println!("empezando por los de prioridad más baja. Si no, cualquier proceso puede ser la víctima.");
+            print!("¿Habilitar el desalojo por prioridad? (s/n): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim().to_lowercase().as_str() {
+                "s" => return true,
+                "n" => return false,
+                _ => println!("Por favor ingrese 's' o 'n'."),
+            }
+        }
+    }
+
+    /// Función para seleccionar la planificación de la CPU
+    fn set_planificacion() -> PlanificacionCpu {
+        loop {
+            println!("\n10. Planificación de la CPU:");
+            println!("El sistema es mono-procesador: de los procesos cargados en memoria, sólo uno ejecuta a la vez.");
+            println!("La duración de un proceso se consume únicamente mientras tiene la CPU.");
+            println!("1) FCFS: por orden de llegada a la cola de listos.");
+            println!("2) SJF: el proceso con menor duración restante, sin expropiación.");
+            println!("3) SRTF: el proceso con menor duración restante, con expropiación.");
+            println!("4) Round-Robin: por orden de llegada, con un quantum de tiempo.");
+            print!("Seleccione una opción (1-4): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return PlanificacionCpu::Fcfs,
+                "2" => return PlanificacionCpu::Sjf,
+                "3" => return PlanificacionCpu::Srtf,
+                "4" => {
+                    return PlanificacionCpu::RoundRobin(Config::pedir_numero(
+                        "Ingrese el quantum (en unidades de tiempo): ",
+                        1,
+                    ) as u64)
+                }
+                _ => println!("Opción no válida. Por favor ingrese 1, 2, 3 o 4."),
+            }
+        }
+    }
+
+    /// Función para pedir la cantidad de dispositivos de E/S
+    fn set_dispositivos_es() -> u32 {
+        println!("\n11. Dispositivos de E/S:");
+        println!("Los procesos con ráfagas de E/S se bloquean en la cola del dispositivo que piden:");
+        println!("siguen ocupando memoria, pero no usan la CPU. Cada dispositivo atiende de a un proceso.");
+        println!("Si un proceso pide un dispositivo que no existe, se atiende en el dispositivo (número % cantidad).");
+        Config::pedir_numero("Ingrese la cantidad de dispositivos de E/S: ", 1)
+    }
+
+    /// Función para seleccionar qué hacer con los procesos que nunca podrán entrar en memoria
+    fn set_insatisfacibles() -> PoliticaInsatisfacibles {
+        loop {
+            println!("\n12. Procesos que nunca entran en memoria:");
+            println!("Un proceso puede pedir más memoria de la que el modelo le puede dar, o quedar esperando");
+            println!("sin que ningún otro proceso pueda liberarle lugar. Se detecta antes y durante la simulación.");
+            println!("1) Rechazar el proceso y seguir con el resto de la tanda.");
+            println!("2) Truncar la tanda: se descartan ese proceso y los que todavía no arribaron.");
+            println!("3) Abortar la simulación con un diagnóstico.");
+            print!("Seleccione una opción (1-3): ");
+            io::stdout().flush().unwrap();
+
+            let mut opcion = String::new();
+            io::stdin().read_line(&mut opcion).expect("Error al leer la opción");
+
+            match opcion.trim() {
+                "1" => return PoliticaInsatisfacibles::Rechazar,
+                "2" => return PoliticaInsatisfacibles::Truncar,
+                "3" => return PoliticaInsatisfacibles::Abortar,
+                _ => println!("Opción no válida. Por favor ingrese 1, 2 o 3."),
+            }
+        }
+    }
+
+    /// Función genérica para pedir un número entero con un valor mínimo
+    fn pedir_numero(mensaje: &str, minimo: u32) -> u32 {
+        loop {
+            let mut numero = String::new();
+            print!("{}", mensaje);
+            io::stdout().flush().unwrap();
+            io::stdin().read_line(&mut numero).expect("Error al leer el número");
+
+            match numero.trim().parse::<u32>() {
+                Ok(numero) if numero >= minimo => return numero,
+                _ => println!("Por favor ingrese un número válido (mayor o igual que {}).", minimo),
+            }
+        }
+    }
+
     /// Función genérica para pedir tiempos (selección, carga, liberación)
+    /// Un tiempo de 0 desactiva esa sobrecarga en la simulación.
     fn pedir_tiempo(tipo: &str) -> u32 {
         loop {
             let mut tiempo = String::new();
-            print!("Ingrese el tiempo de {} (en milisegundos): ", tipo);
+            print!("Ingrese el tiempo de {} (en unidades de tiempo, 0 para omitirlo): ", tipo);
             io::stdout().flush().unwrap();
             io::stdin().read_line(&mut tiempo).expect("Error al leer el tiempo");
 
             match tiempo.trim().parse::<u32>() {
-                Ok(tiempo) if tiempo > 0 => return tiempo,
-                _ => println!("Por favor ingrese un valor válido para el tiempo (en milisegundos)."),
+                Ok(tiempo) => return tiempo,
+                _ => println!("Por favor ingrese un valor válido para el tiempo (número mayor o igual que 0)."),
             }
         }
     }
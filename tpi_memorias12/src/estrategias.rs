@@ -1,190 +1,810 @@
-use crate::particion::Particion;
+use crate::adaptativa::EstrategiaAdaptativa;
+use crate::particion::{self, Particion};
 use crate::proceso::Proceso;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
-#[derive(Debug)]
-pub enum EstrategiaAsignacion {
-    FirstFit,
-    BestFit,
-    NextFit,
-    WorstFit,
-}
+/// Regla para elegir en qué hueco se ubica un proceso. La estrategia sólo lee la memoria y
+/// decide; dividir el hueco y ocupar la partición queda a cargo del modelo de memoria.
+pub trait EstrategiaAsignacion {
+    /// Nombre con el que la estrategia está registrada, con sus parámetros si tiene;
+    /// aparece en los archivos de resultados
+    fn nombre(&self) -> String;
+
+    /// Elige, sin modificar la memoria, el hueco donde ubicar al proceso
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision;
+
+    /// Copia de la estrategia, para que cada simulación use la suya
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion>;
+
+    /// Avisa que transcurrieron `duracion` unidades de tiempo, para las estrategias que
+    /// acumulan métricas ponderadas por tiempo
+    fn avanzar_tiempo(&mut self, _duracion: u64) {}
+
+    /// Devuelve y descarta los mensajes que la estrategia quiere dejar en el registro de eventos
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Resultados propios de la estrategia para el informe final
+    fn resumen(&self, _tiempo_total: u64) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Avisa que se liberó el bloque que comenzaba en `direccion`, para las estrategias que
+    /// recuerdan dónde quedaron huecos
+    fn hueco_liberado(&mut self, _direccion: u32) {}
 
-impl EstrategiaAsignacion {
-    pub fn asignar_proceso(
-        &self,
+    /// Avisa que el modelo ocupó de verdad el último hueco elegido. Las elecciones hechas sobre
+    /// una copia de la memoria que después se descarta no se confirman.
+    fn asignacion_confirmada(&mut self) {}
+
+    /// Asigna el proceso según la estrategia y devuelve la dirección de comienzo del bloque asignado.
+    /// `ultima_asignada` es la dirección donde terminó la última asignación (usada por Next-fit).
+    fn asignar_proceso(
+        &mut self,
         particiones: &mut Vec<Particion>,
         proceso: &Proceso,
         ultima_asignada: &mut usize,
-    ) -> Option<usize> {
-        match self {
-            EstrategiaAsignacion::FirstFit => first_fit(particiones, proceso),
-            EstrategiaAsignacion::BestFit => best_fit(particiones, proceso),
-            EstrategiaAsignacion::NextFit => next_fit(particiones, proceso, ultima_asignada),
-            EstrategiaAsignacion::WorstFit => worst_fit(particiones, proceso),
+        tiempo_carga: u32,
+        busquedas: &mut EstadisticasBusqueda,
+    ) -> Option<u32> {
+        let (indice, direccion) = self.buscar_hueco(particiones, proceso, *ultima_asignada as u32, busquedas)?;
+
+        // Dividir el hueco elegido y ocupar la partición resultante
+        let memoria_requerida = proceso.get_memoria_requerida();
+        let indice_asignado = particion::dividir_hueco(particiones, indice, direccion, memoria_requerida);
+        particiones[indice_asignado].ocupar(
+            proceso.nombre.clone(),
+            proceso.arribo as u32,
+            tiempo_carga,
+        );
+
+        let tamanio_memoria: u32 = particiones.iter().map(|p| p.tamanio).sum();
+        *ultima_asignada = ((direccion + memoria_requerida) % tamanio_memoria) as usize;
+        Some(direccion)
+    }
+
+    /// Busca, sin modificar la memoria, la partición libre donde ubicar al proceso.
+    /// Devuelve el índice de la partición y la dirección donde comenzaría el bloque,
+    /// y suma el trabajo de la búsqueda a `busquedas`.
+    fn buscar_hueco(
+        &mut self,
+        particiones: &[Particion],
+        proceso: &Proceso,
+        ultima_asignada: u32,
+        busquedas: &mut EstadisticasBusqueda,
+    ) -> Option<(usize, u32)> {
+        let mapa = MapaMemoria { particiones, ultima_asignada };
+        let decision = self.elegir(&mapa, proceso);
+        busquedas.registrar(decision.costo);
+        decision.hueco
+    }
+}
+
+/// Lo que una estrategia ve de la memoria al elegir un hueco
+pub struct MapaMemoria<'a> {
+    pub particiones: &'a [Particion], // Libres y ocupadas, en orden de dirección
+    pub ultima_asignada: u32,         // Dirección donde terminó la última asignación
+}
+
+/// Hueco elegido por una estrategia (índice de la partición y dirección donde comienza el
+/// bloque), si hay alguno que alcance, y el trabajo que le llevó encontrarlo
+#[derive(Debug, Clone, Copy)]
+pub struct Decision {
+    pub hueco: Option<(usize, u32)>,
+    pub costo: CostoBusqueda,
+}
+
+/// Pide un número por consola con un mensaje y un valor mínimo
+pub type PedirNumero = fn(&str, u32) -> u32;
+
+/// Estrategia que ofrece el menú de configuración
+pub struct EstrategiaRegistrada {
+    pub nombre: &'static str,
+    pub descripcion: &'static str, // Texto de la opción en el menú
+    pub crear: fn(PedirNumero) -> Box<dyn EstrategiaAsignacion>, // Pide los parámetros que necesite y la crea
+}
+
+/// Estrategias de asignación disponibles, en el orden en que aparecen en el menú
+pub struct RegistroEstrategias {
+    estrategias: Vec<EstrategiaRegistrada>,
+}
+
+impl RegistroEstrategias {
+    /// Registro con las estrategias del simulador. Para sumar una estrategia propia alcanza con
+    /// implementar `EstrategiaAsignacion` y registrarla acá: el menú y los archivos la toman solos.
+    pub fn nuevo() -> RegistroEstrategias {
+        let mut registro = RegistroEstrategias { estrategias: Vec::new() };
+        registro.registrar(
+            "FirstFit",
+            "First-fit (Primer ajuste): Asigna el primer espacio libre que sea suficiente.",
+            |_| Box::new(FirstFit),
+        );
+        registro.registrar(
+            "BestFit",
+            "Best-fit (Mejor ajuste): Busca la partición más pequeña posible que sea suficiente.",
+            |_| Box::new(BestFit),
+        );
+        registro.registrar(
+            "NextFit",
+            "Next-fit circular (Siguiente ajuste): Busca el siguiente espacio libre desde el último utilizado y al pasar el final sigue desde el comienzo.",
+            |_| Box::new(NextFit),
+        );
+        registro.registrar(
+            "NextFitTrasLiberacion",
+            "Next-fit tras liberación: Como Next-fit, pero la búsqueda salta al hueco que dejó el último proceso liberado.",
+            |_| Box::new(NextFitTrasLiberacion::default()),
+        );
+        registro.registrar(
+            "WorstFit",
+            "Worst-fit (Peor ajuste): Busca la partición más grande disponible.",
+            |_| Box::new(WorstFit),
+        );
+        registro.registrar(
+            "LastFit",
+            "Last-fit (Último ajuste): Busca desde la dirección más alta y ubica el bloque al final del hueco.",
+            |_| Box::new(LastFit),
+        );
+        registro.registrar(
+            "RandomFit",
+            "Random-fit (Ajuste aleatorio): Elige al azar entre los huecos que alcanzan (con semilla).",
+            |pedir| Box::new(RandomFit::nueva(pedir("Ingrese la semilla del generador aleatorio: ", 0) as u64)),
+        );
+        registro.registrar(
+            "GoodFit",
+            "Good-fit (Buen ajuste): Toma el primer hueco que sobre a lo sumo una holgura; si no hay, el más ajustado.",
+            |pedir| Box::new(GoodFit { holgura: pedir("Ingrese la holgura aceptada (en KB): ", 0) }),
+        );
+        registro.registrar(
+            "HalfFit",
+            "Half-fit (Ajuste por clases): Agrupa los huecos en clases de potencias de dos y busca desde la clase que seguro alcanza.",
+            |_| Box::new(HalfFit),
+        );
+        registro.registrar(
+            "Adaptativa",
+            "Adaptativa: Alterna entre First-fit, Best-fit y Worst-fit según la fragmentación y los huecos chicos.",
+            |pedir| {
+                Box::new(EstrategiaAdaptativa::nueva(
+                    pedir("Ingrese el tamaño por debajo del cual un hueco es una astilla (en KB): ", 1),
+                    pedir("Ingrese la cantidad de astillas a partir de la cual se usa Worst-fit (0 para nunca): ", 0),
+                    pedir("Ingrese la fragmentación externa (%) a partir de la cual se usa Best-fit: ", 0),
+                ))
+            },
+        );
+        registro
+    }
+
+    /// Agrega una estrategia al final del menú. Los nombres no se pueden repetir.
+    pub fn registrar(
+        &mut self,
+        nombre: &'static str,
+        descripcion: &'static str,
+        crear: fn(PedirNumero) -> Box<dyn EstrategiaAsignacion>,
+    ) {
+        assert!(
+            self.estrategias.iter().all(|e| e.nombre != nombre),
+            "La estrategia {} ya está registrada",
+            nombre
+        );
+        self.estrategias.push(EstrategiaRegistrada { nombre, descripcion, crear });
+    }
+
+    /// Estrategias registradas, en orden
+    pub fn estrategias(&self) -> &[EstrategiaRegistrada] {
+        &self.estrategias
+    }
+}
+
+/// Trabajo que hizo una búsqueda de hueco
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CostoBusqueda {
+    pub inspeccionadas: u32, // Particiones (libres u ocupadas) que recorrió la estrategia
+    pub comparadas: u32,     // Huecos suficientes que consideró como candidatos
+    pub particiones: u32,    // Particiones que había en memoria al buscar
+}
+
+impl CostoBusqueda {
+    /// Costo de una búsqueda que recorrió toda la memoria
+    fn recorrido_completo(particiones: &[Particion], comparadas: u32) -> CostoBusqueda {
+        CostoBusqueda {
+            inspeccionadas: particiones.len() as u32,
+            comparadas,
+            particiones: particiones.len() as u32,
         }
     }
 }
 
-pub fn first_fit(particiones: &mut Vec<Particion>, proceso: &Proceso) -> Option<usize> {
-    let memoria_requerida = proceso.memoria_requerida as usize; // Número de particiones necesarias
-    let mut indice_inicial = None;
-    let mut particiones_contiguas = 0;
+/// Búsquedas de hueco de una simulación y el trabajo que hicieron entre todas
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EstadisticasBusqueda {
+    pub busquedas: u64,
+    pub inspeccionadas: u64,
+    pub comparadas: u64,
+    pub particiones: u64,
+}
 
-    for (index, particion) in particiones.iter().enumerate() {
-        if particion.esta_libre() {
-            if indice_inicial.is_none() {
-                indice_inicial = Some(index);
-            }
-            particiones_contiguas += 1;
-
-            if particiones_contiguas >= memoria_requerida {
-                // Ocupar las particiones desde indice_inicial hasta index inclusive
-                for i in indice_inicial.unwrap()..=index {
-                    particiones[i].ocupar(
-                        proceso.nombre.clone(),
-                        proceso.arribo as u32,
-                        proceso.duracion as u32,
-                    );
-                }
-                return indice_inicial; // Retornamos el índice inicial del bloque asignado
-            }
+impl EstadisticasBusqueda {
+    pub fn registrar(&mut self, costo: CostoBusqueda) {
+        self.busquedas += 1;
+        self.inspeccionadas += costo.inspeccionadas as u64;
+        self.comparadas += costo.comparadas as u64;
+        self.particiones += costo.particiones as u64;
+    }
+
+    /// Particiones recorridas en promedio por búsqueda (longitud media de búsqueda)
+    pub fn longitud_media(&self) -> f64 {
+        if self.busquedas == 0 {
+            0.0
         } else {
-            // Si encontramos una partición ocupada, reiniciamos la búsqueda
-            indice_inicial = None;
-            particiones_contiguas = 0;
+            self.inspeccionadas as f64 / self.busquedas as f64
+        }
+    }
+
+    /// Huecos candidatos comparados en promedio por búsqueda
+    pub fn comparadas_media(&self) -> f64 {
+        if self.busquedas == 0 {
+            0.0
+        } else {
+            self.comparadas as f64 / self.busquedas as f64
+        }
+    }
+
+    /// Porcentaje de las particiones en memoria que recorrieron las búsquedas
+    pub fn porcentaje_recorrido(&self) -> f64 {
+        if self.particiones == 0 {
+            0.0
+        } else {
+            self.inspeccionadas as f64 * 100.0 / self.particiones as f64
         }
     }
-    None // No se encontró un bloque adecuado
 }
 
+// Estrategias clásicas: cada una delega en la función de búsqueda correspondiente
 
-pub fn best_fit(particiones: &mut Vec<Particion>, proceso: &Proceso) -> Option<usize> {
-    let memoria_requerida = proceso.memoria_requerida as usize;
-    let mut mejor_indice = None;
-    let mut mejor_tamanio = usize::MAX;
-    let mut indice_inicial = None;
-    let mut particiones_contiguas = 0;
+#[derive(Debug, Clone)]
+pub struct FirstFit;
 
-    for (index, particion) in particiones.iter().enumerate() {
-        if particion.esta_libre() {
-            if indice_inicial.is_none() {
-                indice_inicial = Some(index);
-            }
-            particiones_contiguas += 1;
-        } else {
-            if particiones_contiguas >= memoria_requerida && particiones_contiguas < mejor_tamanio {
-                mejor_indice = indice_inicial;
-                mejor_tamanio = particiones_contiguas;
-            }
-            indice_inicial = None;
-            particiones_contiguas = 0;
+impl EstrategiaAsignacion for FirstFit {
+    fn nombre(&self) -> String {
+        "FirstFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        first_fit(mapa.particiones, proceso)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BestFit;
+
+impl EstrategiaAsignacion for BestFit {
+    fn nombre(&self) -> String {
+        "BestFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        best_fit(mapa.particiones, proceso)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NextFit;
+
+impl EstrategiaAsignacion for NextFit {
+    fn nombre(&self) -> String {
+        "NextFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        next_fit(mapa.particiones, proceso, mapa.ultima_asignada)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+/// Next-fit cuyo puntero, además de avanzar con cada asignación, salta al comienzo del hueco
+/// que deja cada bloque liberado
+#[derive(Debug, Clone, Default)]
+pub struct NextFitTrasLiberacion {
+    liberada: Option<u32>, // Dirección del último bloque liberado desde la última asignación
+}
+
+impl EstrategiaAsignacion for NextFitTrasLiberacion {
+    fn nombre(&self) -> String {
+        "NextFitTrasLiberacion".to_string()
+    }
+
+    /// Si el bloque liberado sigue libre, la búsqueda parte del comienzo del hueco que lo contiene
+    /// (que puede haberse combinado con sus vecinos); si no, de donde terminó la última asignación.
+    /// El salto se mantiene hasta que el modelo confirma una asignación.
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        let puntero = self
+            .liberada
+            .and_then(|direccion| {
+                mapa.particiones
+                    .iter()
+                    .find(|p| p.direccion_comienzo <= direccion && direccion < p.direccion_fin())
+            })
+            .filter(|p| p.esta_libre())
+            .map_or(mapa.ultima_asignada, |p| p.direccion_comienzo);
+
+        next_fit(mapa.particiones, proceso, puntero)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+
+    fn hueco_liberado(&mut self, direccion: u32) {
+        self.liberada = Some(direccion);
+    }
+
+    fn asignacion_confirmada(&mut self) {
+        self.liberada = None;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorstFit;
+
+impl EstrategiaAsignacion for WorstFit {
+    fn nombre(&self) -> String {
+        "WorstFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        worst_fit(mapa.particiones, proceso)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LastFit;
+
+impl EstrategiaAsignacion for LastFit {
+    fn nombre(&self) -> String {
+        "LastFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        last_fit(mapa.particiones, proceso)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+/// Random-fit guarda su semilla: cada simulación vuelve a empezar la misma secuencia
+#[derive(Debug, Clone)]
+pub struct RandomFit {
+    semilla: u64,
+    rng: StdRng,
+}
+
+impl RandomFit {
+    pub fn nueva(semilla: u64) -> RandomFit {
+        RandomFit {
+            semilla,
+            rng: StdRng::seed_from_u64(semilla),
         }
     }
+}
+
+impl EstrategiaAsignacion for RandomFit {
+    fn nombre(&self) -> String {
+        format!("RandomFit({})", self.semilla)
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        random_fit(mapa.particiones, proceso, &mut self.rng)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(RandomFit::nueva(self.semilla))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GoodFit {
+    pub holgura: u32, // KB que puede sobrar en el hueco para aceptarlo sin seguir buscando
+}
+
+impl EstrategiaAsignacion for GoodFit {
+    fn nombre(&self) -> String {
+        format!("GoodFit({}KB)", self.holgura)
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        good_fit(mapa.particiones, proceso, self.holgura)
+    }
 
-    // Verificar al final del vector
-    if particiones_contiguas >= memoria_requerida && particiones_contiguas < mejor_tamanio {
-        mejor_indice = indice_inicial;
-        mejor_tamanio = particiones_contiguas;
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
     }
+}
+
+#[derive(Debug, Clone)]
+pub struct HalfFit;
+
+impl EstrategiaAsignacion for HalfFit {
+    fn nombre(&self) -> String {
+        "HalfFit".to_string()
+    }
+
+    fn elegir(&mut self, mapa: &MapaMemoria, proceso: &Proceso) -> Decision {
+        half_fit(mapa.particiones, proceso)
+    }
+
+    fn clonar(&self) -> Box<dyn EstrategiaAsignacion> {
+        Box::new(self.clone())
+    }
+}
+
+// Cada estrategia devuelve el índice del hueco elegido y la dirección donde comienza el bloque,
+// junto con el trabajo que le llevó encontrarlo.
+
+pub fn first_fit(particiones: &[Particion], proceso: &Proceso) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida(); // KB necesarios
 
-    if let Some(indice) = mejor_indice {
-        let fin = indice + memoria_requerida;
-        for i in indice..fin {
-            particiones[i].ocupar(
-                proceso.nombre.clone(),
-                proceso.arribo as u32,
-                proceso.duracion as u32,
-            );
+    for (index, particion) in particiones.iter().enumerate() {
+        if particion.esta_libre() && particion.tamanio >= memoria_requerida {
+            // Primer hueco suficiente: no hace falta seguir recorriendo
+            let costo = CostoBusqueda {
+                inspeccionadas: index as u32 + 1,
+                comparadas: 1,
+                particiones: particiones.len() as u32,
+            };
+            return Decision { hueco: Some((index, particion.direccion_comienzo)), costo };
         }
-        return Some(indice);
     }
-    None
+    // No se encontró un bloque adecuado
+    Decision { hueco: None, costo: CostoBusqueda::recorrido_completo(particiones, 0) }
 }
 
 
+pub fn best_fit(particiones: &[Particion], proceso: &Proceso) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
+    let mut mejor_indice = None;
+    let mut mejor_tamanio = u32::MAX;
+    let mut comparadas = 0;
+
+    for (index, particion) in particiones.iter().enumerate() {
+        if particion.esta_libre() && particion.tamanio >= memoria_requerida {
+            comparadas += 1;
+            if particion.tamanio < mejor_tamanio {
+                mejor_indice = Some(index);
+                mejor_tamanio = particion.tamanio;
+            }
+        }
+    }
+
+    // Siempre recorre toda la memoria
+    let costo = CostoBusqueda::recorrido_completo(particiones, comparadas);
+    Decision {
+        hueco: mejor_indice.map(|indice| (indice, particiones[indice].direccion_comienzo)),
+        costo,
+    }
+}
+
+
+/// Next-fit continúa la búsqueda desde la dirección donde terminó la última asignación.
+/// Si esa dirección cae en medio de un hueco, el bloque puede comenzar en ella;
+/// al llegar al final de la memoria se sigue desde el comienzo. El bloque siempre queda
+/// dentro de un único hueco: un hueco al final y otro al comienzo de la memoria no se suman.
 pub fn next_fit(
-    particiones: &mut Vec<Particion>,
+    particiones: &[Particion],
     proceso: &Proceso,
-    ultima_asignada: &mut usize,
-) -> Option<usize> {
-    let memoria_requerida = proceso.memoria_requerida as usize;
-    let mut indice_inicial = None;
-    let mut particiones_contiguas = 0;
+    ultima_asignada: u32,
+) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
     let n = particiones.len();
-    let mut index = *ultima_asignada;
-
-    let mut vueltas = 0;
-    while vueltas < n {
-        let particion = &particiones[index % n];
-        if particion.esta_libre() {
-            if indice_inicial.is_none() {
-                indice_inicial = Some(index % n);
-            }
-            particiones_contiguas += 1;
-            if particiones_contiguas >= memoria_requerida {
-                let inicio = indice_inicial.unwrap();
-                let fin = (inicio + memoria_requerida) % n;
-                for i in 0..memoria_requerida {
-                    let idx = (inicio + i) % n;
-                    particiones[idx].ocupar(
-                        proceso.nombre.clone(),
-                        proceso.arribo as u32,
-                        proceso.duracion as u32,
-                    );
-                }
-                *ultima_asignada = fin % n;
-                return Some(inicio);
-            }
+    let tamanio_memoria = particiones[n - 1].direccion_fin();
+    let ultima_asignada = ultima_asignada % tamanio_memoria; // Al final de la memoria se vuelve al comienzo
+
+    // Partición que contiene la dirección de la última asignación
+    let inicio = particiones
+        .iter()
+        .position(|p| p.direccion_fin() > ultima_asignada)
+        .unwrap_or(0);
+
+    for vuelta in 0..=n {
+        let index = (inicio + vuelta) % n;
+        let particion = &particiones[index];
+        if !particion.esta_libre() {
+            continue;
+        }
+
+        // En el hueco de partida se considera primero el tramo desde la dirección de la última asignación,
+        // y al completar la vuelta el hueco entero desde su comienzo
+        let desde = if vuelta == 0 {
+            ultima_asignada.max(particion.direccion_comienzo)
         } else {
-            indice_inicial = None;
-            particiones_contiguas = 0;
+            particion.direccion_comienzo
+        };
+        let hasta = particion.direccion_fin();
+
+        if hasta - desde >= memoria_requerida {
+            // El hueco de partida cuenta una sola vez aunque se vuelva a él al completar la vuelta
+            let costo = CostoBusqueda {
+                inspeccionadas: (vuelta + 1).min(n) as u32,
+                comparadas: 1,
+                particiones: n as u32,
+            };
+            return Decision { hueco: Some((index, desde)), costo };
         }
-        index += 1;
-        vueltas += 1;
     }
-    None
+    Decision { hueco: None, costo: CostoBusqueda::recorrido_completo(particiones, 0) }
 }
 
 
-pub fn worst_fit(particiones: &mut Vec<Particion>, proceso: &Proceso) -> Option<usize> {
-    let memoria_requerida = proceso.memoria_requerida as usize;
+pub fn worst_fit(particiones: &[Particion], proceso: &Proceso) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
     let mut peor_indice = None;
     let mut peor_tamanio = 0;
-    let mut indice_inicial = None;
-    let mut particiones_contiguas = 0;
+    let mut comparadas = 0;
 
     for (index, particion) in particiones.iter().enumerate() {
-        if particion.esta_libre() {
-            if indice_inicial.is_none() {
-                indice_inicial = Some(index);
+        if particion.esta_libre() && particion.tamanio >= memoria_requerida {
+            comparadas += 1;
+            if particion.tamanio > peor_tamanio {
+                peor_indice = Some(index);
+                peor_tamanio = particion.tamanio;
             }
-            particiones_contiguas += 1;
-        } else {
-            if particiones_contiguas >= memoria_requerida && particiones_contiguas > peor_tamanio {
-                peor_indice = indice_inicial;
-                peor_tamanio = particiones_contiguas;
+        }
+    }
+
+    // Siempre recorre toda la memoria
+    let costo = CostoBusqueda::recorrido_completo(particiones, comparadas);
+    Decision {
+        hueco: peor_indice.map(|indice| (indice, particiones[indice].direccion_comienzo)),
+        costo,
+    }
+}
+
+
+/// Last-fit recorre la memoria desde la dirección más alta y ubica el bloque al final del
+/// primer hueco que alcanza, de modo que la memoria se llena de arriba hacia abajo.
+pub fn last_fit(particiones: &[Particion], proceso: &Proceso) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
+
+    for (recorridas, (index, particion)) in particiones.iter().enumerate().rev().enumerate() {
+        if particion.esta_libre() && particion.tamanio >= memoria_requerida {
+            let costo = CostoBusqueda {
+                inspeccionadas: recorridas as u32 + 1,
+                comparadas: 1,
+                particiones: particiones.len() as u32,
+            };
+            return Decision { hueco: Some((index, particion.direccion_fin() - memoria_requerida)), costo };
+        }
+    }
+    Decision { hueco: None, costo: CostoBusqueda::recorrido_completo(particiones, 0) }
+}
+
+
+/// Random-fit elige con igual probabilidad cualquiera de los huecos que alcanzan.
+pub fn random_fit(particiones: &[Particion], proceso: &Proceso, rng: &mut StdRng) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
+    let candidatos: Vec<usize> = particiones
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.esta_libre() && p.tamanio >= memoria_requerida)
+        .map(|(index, _)| index)
+        .collect();
+
+    // Siempre recorre toda la memoria para conocer a todos los candidatos
+    let costo = CostoBusqueda::recorrido_completo(particiones, candidatos.len() as u32);
+    if candidatos.is_empty() {
+        return Decision { hueco: None, costo };
+    }
+    let index = candidatos[rng.gen_range(0..candidatos.len())];
+    Decision { hueco: Some((index, particiones[index].direccion_comienzo)), costo }
+}
+
+
+/// Good-fit toma el primer hueco donde sobran a lo sumo `holgura` KB. Si ninguno es tan ajustado,
+/// se queda con el más ajustado de los que alcanzan (como Best-fit).
+pub fn good_fit(particiones: &[Particion], proceso: &Proceso, holgura: u32) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida();
+    let mut mejor_indice = None;
+    let mut mejor_tamanio = u32::MAX;
+    let mut comparadas = 0;
+
+    for (index, particion) in particiones.iter().enumerate() {
+        if !particion.esta_libre() || particion.tamanio < memoria_requerida {
+            continue;
+        }
+        comparadas += 1;
+        if particion.tamanio - memoria_requerida <= holgura {
+            let costo = CostoBusqueda {
+                inspeccionadas: index as u32 + 1,
+                comparadas,
+                particiones: particiones.len() as u32,
+            };
+            return Decision { hueco: Some((index, particion.direccion_comienzo)), costo };
+        }
+        if particion.tamanio < mejor_tamanio {
+            mejor_indice = Some(index);
+            mejor_tamanio = particion.tamanio;
+        }
+    }
+
+    let costo = CostoBusqueda::recorrido_completo(particiones, comparadas);
+    Decision {
+        hueco: mejor_indice.map(|indice| (indice, particiones[indice].direccion_comienzo)),
+        costo,
+    }
+}
+
+
+/// Clase de un hueco para Half-fit: la clase k agrupa los huecos de 2^k a 2^(k+1) - 1 KB
+fn clase_half_fit(tamanio: u32) -> u32 {
+    31 - tamanio.leading_zeros()
+}
+
+/// Half-fit agrupa los huecos en clases por potencias de dos. Cualquier hueco de una clase mayor
+/// o igual a la que redondea hacia arriba el pedido alcanza seguro, así que se toma el primero
+/// de la menor de esas clases sin revisar tamaños; sólo si todas están vacías se busca un hueco
+/// que alcance dentro de la clase del pedido.
+pub fn half_fit(particiones: &[Particion], proceso: &Proceso) -> Decision {
+    let memoria_requerida = proceso.get_memoria_requerida().max(1);
+    let clase_pedido = clase_half_fit(memoria_requerida);
+    let clase_segura = if memoria_requerida.is_power_of_two() { clase_pedido } else { clase_pedido + 1 };
+
+    // Primer hueco de cada clase, en orden de dirección
+    let mut primero_de_clase: Vec<Option<usize>> = vec![None; 32];
+    for (index, particion) in particiones.iter().enumerate() {
+        if particion.esta_libre() && particion.tamanio > 0 {
+            primero_de_clase[clase_half_fit(particion.tamanio) as usize].get_or_insert(index);
+        }
+    }
+    // Los huecos se clasifican recorriendo toda la memoria
+    let mut costo = CostoBusqueda::recorrido_completo(particiones, 0);
+
+    if let Some(index) = primero_de_clase[clase_segura as usize..].iter().find_map(|primero| *primero) {
+        costo.comparadas = 1;
+        return Decision { hueco: Some((index, particiones[index].direccion_comienzo)), costo };
+    }
+
+    // En la clase del pedido hay huecos que alcanzan y otros que no
+    for (index, particion) in particiones.iter().enumerate() {
+        if particion.esta_libre() && particion.tamanio > 0 && clase_half_fit(particion.tamanio) == clase_pedido {
+            costo.comparadas += 1;
+            if particion.tamanio >= memoria_requerida {
+                return Decision { hueco: Some((index, particion.direccion_comienzo)), costo };
+            }
+        }
+    }
+    Decision { hueco: None, costo }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Memoria contigua desde la dirección 0 a partir de (tamaño, libre) de cada partición
+    fn memoria(bloques: &[(u32, bool)]) -> Vec<Particion> {
+        let mut particiones = Vec::new();
+        let mut direccion = 0;
+        for &(tamanio, libre) in bloques {
+            let mut particion = Particion::nueva(direccion, tamanio);
+            if !libre {
+                particion.ocupar(format!("Q{}", particiones.len()), 0, 0);
             }
-            indice_inicial = None;
-            particiones_contiguas = 0;
+            particiones.push(particion);
+            direccion += tamanio;
         }
+        particiones
+    }
+
+    fn elegir(
+        estrategia: &mut dyn EstrategiaAsignacion,
+        particiones: &[Particion],
+        ultima_asignada: u32,
+        memoria_requerida: u32,
+    ) -> Option<(usize, u32)> {
+        let mapa = MapaMemoria { particiones, ultima_asignada };
+        estrategia.elegir(&mapa, &Proceso::new("P", 0, 1, memoria_requerida)).hueco
     }
 
-    // Verificar al final del vector
-    if particiones_contiguas >= memoria_requerida && particiones_contiguas > peor_tamanio {
-        peor_indice = indice_inicial;
-        peor_tamanio = particiones_contiguas;
+    /// El bloque elegido tiene que caber entero en una única partición libre
+    fn assert_contiguo(particiones: &[Particion], hueco: Option<(usize, u32)>, memoria_requerida: u32) {
+        if let Some((indice, direccion)) = hueco {
+            let particion = &particiones[indice];
+            assert!(particion.esta_libre());
+            assert!(direccion >= particion.direccion_comienzo);
+            assert!(direccion + memoria_requerida <= particion.direccion_fin());
+        }
+    }
+
+    #[test]
+    fn ninguna_variante_suma_el_hueco_final_con_el_inicial() {
+        // 30 KB libres al final y 30 al comienzo: juntos alcanzarían para 50, pero no son contiguos
+        let particiones = memoria(&[(30, true), (40, false), (30, true)]);
+        assert_eq!(elegir(&mut NextFit, &particiones, 70, 50), None);
+
+        let mut tras_liberacion = NextFitTrasLiberacion::default();
+        tras_liberacion.hueco_liberado(70);
+        assert_eq!(elegir(&mut tras_liberacion, &particiones, 70, 50), None);
     }
 
-    if let Some(indice) = peor_indice {
-        let fin = indice + memoria_requerida;
-        for i in indice..fin {
-            particiones[i].ocupar(
-                proceso.nombre.clone(),
-                proceso.arribo as u32,
-                proceso.duracion as u32,
-            );
+    #[test]
+    fn circular_sigue_desde_el_comienzo_al_pasar_el_final() {
+        let particiones = memoria(&[(40, true), (40, false), (20, true)]);
+        assert_eq!(elegir(&mut NextFit, &particiones, 80, 30), Some((0, 0)));
+        // Un puntero al final de la memoria vuelve a la dirección 0
+        assert_eq!(elegir(&mut NextFit, &particiones, 100, 30), Some((0, 0)));
+    }
+
+    #[test]
+    fn circular_usa_el_hueco_de_partida_entero_al_completar_la_vuelta() {
+        // Ni el tramo antes ni el tramo después del puntero alcanzan, pero el hueco entero sí
+        let particiones = memoria(&[(100, true), (20, false)]);
+        assert_eq!(elegir(&mut NextFit, &particiones, 50, 80), Some((0, 0)));
+    }
+
+    #[test]
+    fn tras_liberacion_el_puntero_salta_al_hueco_liberado() {
+        let particiones = memoria(&[(50, true), (20, false), (50, true)]);
+        assert_eq!(elegir(&mut NextFit, &particiones, 70, 40), Some((2, 70)));
+
+        let mut tras_liberacion = NextFitTrasLiberacion::default();
+        tras_liberacion.hueco_liberado(10);
+        assert_eq!(elegir(&mut tras_liberacion, &particiones, 70, 40), Some((0, 0)));
+        // Mientras no se confirme una asignación, el salto se mantiene
+        assert_eq!(elegir(&mut tras_liberacion, &particiones, 70, 40), Some((0, 0)));
+        // Después de asignar vuelve a seguir a la última asignación
+        tras_liberacion.asignacion_confirmada();
+        assert_eq!(elegir(&mut tras_liberacion, &particiones, 70, 40), Some((2, 70)));
+    }
+
+    #[test]
+    fn tras_liberacion_ignora_un_bloque_liberado_que_ya_se_ocupo() {
+        let particiones = memoria(&[(50, false), (20, false), (50, true)]);
+        let mut tras_liberacion = NextFitTrasLiberacion::default();
+        tras_liberacion.hueco_liberado(0);
+        assert_eq!(elegir(&mut tras_liberacion, &particiones, 70, 40), Some((2, 70)));
+    }
+
+    #[test]
+    fn ninguna_variante_devuelve_un_bloque_partido() {
+        let mut rng = StdRng::seed_from_u64(12);
+        for _ in 0..500 {
+            let bloques: Vec<(u32, bool)> = (0..rng.gen_range(1..8))
+                .map(|_| (rng.gen_range(1..60), rng.gen_bool(0.5)))
+                .collect();
+            let particiones = memoria(&bloques);
+            let tamanio_memoria = particiones[particiones.len() - 1].direccion_fin();
+
+            for ultima_asignada in 0..=tamanio_memoria {
+                let memoria_requerida = rng.gen_range(1..=tamanio_memoria);
+                let hueco = elegir(&mut NextFit, &particiones, ultima_asignada, memoria_requerida);
+                assert_contiguo(&particiones, hueco, memoria_requerida);
+
+                let mut tras_liberacion = NextFitTrasLiberacion::default();
+                tras_liberacion.hueco_liberado(rng.gen_range(0..tamanio_memoria));
+                let hueco_tras_liberacion = elegir(&mut tras_liberacion, &particiones, ultima_asignada, memoria_requerida);
+                assert_contiguo(&particiones, hueco_tras_liberacion, memoria_requerida);
+
+                // Si algún hueco alcanza, las dos variantes lo encuentran
+                let alcanza = particiones.iter().any(|p| p.esta_libre() && p.tamanio >= memoria_requerida);
+                assert_eq!(hueco.is_some(), alcanza);
+                assert_eq!(hueco_tras_liberacion.is_some(), alcanza);
+            }
         }
-        return Some(indice);
     }
-    None
 }
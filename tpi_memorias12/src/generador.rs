@@ -1,12 +1,23 @@
-use crate::proceso::Proceso;
+use crate::manipular_archivo::ManipularArchivo;
+use crate::proceso::{CambioMemoria, PedidoObjetos, Proceso, Rafaga, Segmento};
+use crate::slab::TIPOS_OBJETO;
 use rand::Rng;
 use std::io::{self, Write};
 
 /// Función que genera un conjunto de procesos
 pub fn generar_procesos() -> Vec<Proceso> {
+    // Paso 0: La tanda también puede leerse de un archivo
+    if pide_origen_archivo() {
+        return cargar_procesos();
+    }
+
     // Paso 1: Solicitar al usuario que ingrese el número de procesos a generar
     let num_procesos = pidenum();
-    let mut procesos = generar_procesos_aleatorios(num_procesos);
+    let con_rafagas_es = pide_si_no("¿Generar procesos con ráfagas de E/S? (s/n): ");
+    let con_cambios_memoria = pide_si_no("¿Generar procesos que pidan o devuelvan memoria mientras ejecutan? (s/n): ");
+    let con_paciencia = pide_si_no("¿Generar procesos que abandonen si esperan memoria demasiado tiempo? (s/n): ");
+    let con_objetos = pide_si_no("¿Generar procesos que pidan objetos del kernel (modo slab)? (s/n): ");
+    let mut procesos = generar_procesos_aleatorios(num_procesos, con_rafagas_es, con_cambios_memoria, con_paciencia, con_objetos);
     
     // Paso 2: Ordenar los procesos por tiempo de arribo
     procesos.sort_by_key(|p| p.arribo);
@@ -17,6 +28,78 @@ pub fn generar_procesos() -> Vec<Proceso> {
     procesos
 }
 
+/// Función que pregunta si la tanda se genera al azar o se lee de un archivo
+fn pide_origen_archivo() -> bool {
+    loop {
+        println!("\n=========================================");
+        println!("Origen de la Tanda de Procesos");
+        println!("=========================================");
+        println!("1) Generar procesos aleatorios.");
+        println!("2) Cargar los procesos desde un archivo.");
+        print!("Seleccione una opción (1-2): ");
+
+        io::stdout().flush().unwrap();
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Error al leer la opción");
+
+        match input.trim() {
+            "1" => return false,
+            "2" => return true,
+            _ => println!("Opción no válida. Por favor ingrese 1 o 2."),
+        }
+    }
+}
+
+/// Función que lee la tanda de un archivo, pidiendo la ruta hasta que se pueda leer
+fn cargar_procesos() -> Vec<Proceso> {
+    loop {
+        println!("\nEl archivo debe tener un proceso por línea: Nombre,Arribo,Duracion,Memoria[,Ráfagas[,Cambios[,Prioridad[,Paciencia[,Objetos[,Segmentos]]]]]]");
+        println!("Las ráfagas son opcionales y se separan con ';' (ej: CPU 3; ES 0 4; CPU 2).");
+        println!("Los cambios de memoria también (ej: 2 +50; 4 -30 pide 50 KB tras ejecutar 2 unidades y devuelve 30 tras 4).");
+        println!("La prioridad es opcional: 0 es la más alta.");
+        println!("La paciencia también: es el tiempo máximo que el proceso espera memoria antes de abandonar.");
+        println!("Los objetos del kernel, para el modo slab, se separan con ';' (ej: tarea 2; inodo 5) y deben sumar la memoria.");
+        println!("Los segmentos también (ej: Código 120; Datos 80; Pila 40) y también deben sumar la memoria.");
+        print!("Ingrese la ruta del archivo de procesos: ");
+
+        io::stdout().flush().unwrap();
+        let mut ruta = String::new();
+        io::stdin().read_line(&mut ruta).expect("Error al leer la ruta");
+
+        match ManipularArchivo::leer_procesos(ruta.trim()) {
+            Ok(procesos) => {
+                for proceso in &procesos {
+                    println!(
+                        "Proceso cargado: | Nombre: {} | Instante de arribo: {} | Duración: {} | Memoria: {}KB | Prioridad: {} | Ráfagas: {} |",
+                        proceso.nombre, proceso.arribo, proceso.duracion, proceso.memoria_requerida, proceso.prioridad, proceso.descripcion_rafagas()
+                    );
+                }
+                println!("\nSe cargaron {} procesos, ordenados por tiempo de arribo.", procesos.len());
+                println!("A continuación, procederemos con la configuración de la simulación.\n");
+                return procesos;
+            }
+            Err(error) => println!("{}", error),
+        }
+    }
+}
+
+/// Función que hace una pregunta de sí o no sobre los procesos a generar
+fn pide_si_no(pregunta: &str) -> bool {
+    loop {
+        print!("{}", pregunta);
+
+        io::stdout().flush().unwrap();
+        let mut input = String::new();
+        io::stdin().read_line(&mut input).expect("Error al leer la respuesta");
+
+        match input.trim().to_lowercase().as_str() {
+            "s" => return true,
+            "n" => return false,
+            _ => println!("Por favor ingrese 's' o 'n'."),
+        }
+    }
+}
+
 /// Función que pide al usuario el número de procesos
 fn pidenum() -> usize {
     loop {
@@ -27,7 +110,7 @@ fn pidenum() -> usize {
         println!("Vamos a generar un conjunto de procesos que serán utilizados");
         println!("en la simulación de asignación de memoria.");
         println!("Cada proceso tendrá un instante de arribo, una duración y");
-        println!("una cantidad de memoria requerida.");
+        println!("una cantidad de memoria requerida, repartida en segmentos de código, datos y pila.");
         println!("\nPor favor, elija cuántos procesos desea generar.");
         println!("Recuerde que este número debe ser mayor que 0.");
         print!("Ingrese el número de procesos a generar: ");
@@ -47,7 +130,13 @@ fn pidenum() -> usize {
 }
 
 /// Función que genera una cantidad de procesos aleatorios
-fn generar_procesos_aleatorios(num_procesos: usize) -> Vec<Proceso> {
+fn generar_procesos_aleatorios(
+    num_procesos: usize,
+    con_rafagas_es: bool,
+    con_cambios_memoria: bool,
+    con_paciencia: bool,
+    con_objetos: bool,
+) -> Vec<Proceso> {
     let mut procesos = Vec::new();
     let mut rng = rand::thread_rng();
 
@@ -57,16 +146,86 @@ fn generar_procesos_aleatorios(num_procesos: usize) -> Vec<Proceso> {
         let nombre = format!("P{}", i);
         let arribo = rng.gen_range(0..20); // Instante de arribo aleatorio
         let duracion = rng.gen_range(5..10); // Duración aleatoria entre 5 y 10 unidades de tiempo
-        let memoria_requerida = rng.gen_range(1..500); // Memoria requerida entre 1KB y 500KB
+        let mut memoria_requerida = rng.gen_range(3..500); // Memoria requerida entre 3KB y 500KB
+        let prioridad = rng.gen_range(0..5); // Prioridad entre 0 (la más alta) y 4
 
-        let proceso = Proceso::new(&nombre, arribo, duracion, memoria_requerida);
-        procesos.push(proceso);
+        let mut proceso = if con_objetos {
+            // Entre 1 y 3 tipos de objeto del kernel, de 1 a 8 objetos de cada uno
+            let mut tipos = TIPOS_OBJETO.to_vec();
+            let objetos = (0..rng.gen_range(1..=3))
+                .map(|_| PedidoObjetos {
+                    tipo: tipos.remove(rng.gen_range(0..tipos.len())),
+                    cantidad: rng.gen_range(1..=8),
+                })
+                .collect();
+            let proceso = Proceso::new(&nombre, arribo, duracion, 0).con_objetos(objetos);
+            memoria_requerida = proceso.get_memoria_requerida();
+            proceso
+        } else {
+            // La memoria se reparte al azar entre los segmentos de código, datos y pila (al menos 1KB cada uno)
+            let codigo = rng.gen_range(1..=memoria_requerida - 2);
+            let datos = rng.gen_range(1..=memoria_requerida - codigo - 1);
+            let pila = memoria_requerida - codigo - datos;
+            let segmentos = vec![
+                Segmento { nombre: "Código".to_string(), tamanio: codigo },
+                Segmento { nombre: "Datos".to_string(), tamanio: datos },
+                Segmento { nombre: "Pila".to_string(), tamanio: pila },
+            ];
+            Proceso::con_segmentos(&nombre, arribo, duracion, segmentos)
+        }
+        .con_prioridad(prioridad);
+
+        // La mitad de los procesos reparte su duración en 2 o 3 ráfagas de CPU separadas por E/S
+        if con_rafagas_es && rng.gen_bool(0.5) {
+            let esperas_es = rng.gen_range(1..=2);
+            let mut restante = duracion as u64;
+            let mut rafagas = Vec::new();
+            for faltan in (1..=esperas_es).rev() {
+                // Cada ráfaga de CPU que falta necesita al menos una unidad
+                let cpu = rng.gen_range(1..=restante - faltan);
+                restante -= cpu;
+                rafagas.push(Rafaga::Cpu(cpu));
+                rafagas.push(Rafaga::EntradaSalida {
+                    dispositivo: rng.gen_range(0..2),
+                    duracion: rng.gen_range(1..6),
+                });
+            }
+            rafagas.push(Rafaga::Cpu(restante));
+            proceso = proceso.con_rafagas(rafagas);
+        }
+
+        // Un tercio de los procesos pide o devuelve memoria una o dos veces mientras ejecuta
+        if con_cambios_memoria && rng.gen_bool(1.0 / 3.0) {
+            let cambios = (0..rng.gen_range(1..=2))
+                .map(|_| CambioMemoria {
+                    tras_ejecutar: rng.gen_range(0..duracion as u64),
+                    cambio: rng.gen_range(-(memoria_requerida as i32) / 2..=150),
+                })
+                .collect();
+            proceso = proceso.con_cambios_memoria(cambios);
+        }
+
+        // Cada proceso espera memoria a lo sumo entre 5 y 25 unidades de tiempo
+        if con_paciencia {
+            proceso = proceso.con_paciencia(rng.gen_range(5..=25));
+        }
 
         // Mostrar el proceso generado
         println!(
-            "Proceso {} generado: | Nombre: {} | Instante de arribo: {} | Duración: {} | Memoria: {}KB |",
-            i, nombre, arribo, duracion, memoria_requerida
+            "Proceso {} generado: | Nombre: {} | Instante de arribo: {} | Duración: {} | Memoria: {}KB ({}) | Prioridad: {} | Paciencia: {} | Ráfagas: {} | Cambios de memoria: {} | Objetos: {} |",
+            i,
+            nombre,
+            arribo,
+            duracion,
+            memoria_requerida,
+            proceso.descripcion_segmentos(),
+            prioridad,
+            proceso.paciencia.map_or("-".to_string(), |p| p.to_string()),
+            proceso.descripcion_rafagas(),
+            proceso.descripcion_cambios_memoria(),
+            proceso.descripcion_objetos()
         );
+        procesos.push(proceso);
     }
 
     procesos
@@ -0,0 +1,9 @@
+/// Política para los procesos que nunca van a poder avanzar: los que piden más memoria de la que
+/// el modelo puede darle a un solo proceso, o los que quedan esperando sin que ningún evento
+/// pendiente pueda hacerles lugar.
+#[derive(Debug, Clone)]
+pub enum PoliticaInsatisfacibles {
+    Rechazar, // Se descarta el proceso y la simulación sigue con el resto de la tanda
+    Truncar,  // Se corta la tanda: se descartan ese proceso y los que todavía no arribaron
+    Abortar,  // Se detiene la simulación con un diagnóstico
+}
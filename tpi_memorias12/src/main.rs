@@ -1,3 +1,6 @@
+mod adaptativa;
+mod admision;
+mod compactacion;
 mod config;
 mod generador;
 mod nueva_simulacion;
@@ -7,10 +10,20 @@ mod proceso;
 mod simulador;
 mod estrategias;
 mod particion;
+mod particiones_fijas;
+mod memoria;
+mod buddy;
+mod paginacion;
+mod segmentacion;
+mod segregada;
+mod slab;
+mod swapping;
+mod planificador;
+mod insatisfacibles;
 
 fn limpiar_consola() {
     if cfg!(target_os = "windows") {
-        std::process::Command::new("cmd").args(&["/C", "cls"]).status().unwrap();
+        std::process::Command::new("cmd").args(["/C", "cls"]).status().unwrap();
     } else {
         std::process::Command::new("clear").status().unwrap();
     }
@@ -40,7 +53,7 @@ fn menu() {
         println!("1) Crear Nueva Simulación");
         println!("2) Ver simulaciones");
         println!("3) Salir");
-        println!("");
+        println!();
         println!("Seleccione una opción (1-3): ");
 
         let mut opcion = String::new();
@@ -1,5 +1,7 @@
-use crate::proceso::Proceso;
+use crate::proceso::{CambioMemoria, PedidoObjetos, Proceso, Rafaga, Segmento};
+use crate::slab::{TipoObjeto, TIPOS_OBJETO};
 use crate::config::Config;
+use crate::simulador::{CicloDeVida, Desenlace, EstadoSimulacion};
 use std::fs::{File};
 use std::io::{BufWriter, Write};
 use std::path::Path;
@@ -7,12 +9,30 @@ use std::path::Path;
 pub struct ManipularArchivo;
 
 impl ManipularArchivo {
+    /// Deja en el nombre de la estrategia sólo letras, números y guiones, para que sirva como
+    /// parte de un nombre de archivo en cualquier sistema (ej: "Adaptativa(4KB-3-30%)" pasa a
+    /// "Adaptativa-4KB-3-30pct")
+    fn nombre_para_archivo(nombre: &str) -> String {
+        let mut limpio = String::new();
+        for caracter in nombre.chars() {
+            match caracter {
+                c if c.is_ascii_alphanumeric() || c == '-' => limpio.push(c),
+                '%' => limpio.push_str("pct"),
+                _ if !limpio.ends_with('-') => limpio.push('-'),
+                _ => {}
+            }
+        }
+        limpio.trim_matches('-').to_string()
+    }
+
     /// Función para crear el archivo de la simulación con procesos, configuración, eventos y resultados
     pub fn crear_archivo_simulacion(
         procesos: &[Proceso],
         configuracion: &Config,
         eventos: &[String],
         resultados: &[String],
+        ciclos_de_vida: &[CicloDeVida],
+        estado: &EstadoSimulacion,
     ) -> String {
         // Contar la cantidad de archivos existentes en "files" para definir el número xx del archivo
         let files_path = Path::new("files");
@@ -23,30 +43,55 @@ impl ManipularArchivo {
             1
         };
 
-        // Crear el nombre del archivo con el formato `XX_procesos(YY)_estrategia(ZZ).txt`
+        // Crear el nombre del archivo con el formato `XX_procesos(YY)_estrategia(ZZ).txt`;
+        // las simulaciones que no terminaron llevan además el sufijo `_incompleta`
         let nombre_archivo = format!(
-            "files/{}_procesos({})_estrategia({:?}).txt",
+            "files/{}_procesos({})_estrategia({}){}.txt",
             archivo_numero,
             procesos.len(),
-            configuracion.estrategia
+            ManipularArchivo::nombre_para_archivo(&configuracion.nombre_estrategia()),
+            match estado {
+                EstadoSimulacion::Completa => "",
+                EstadoSimulacion::Incompleta(_) => "_incompleta",
+            }
         );
         let path = Path::new(&nombre_archivo);
 
         // Crear o abrir el archivo para escribir
-        let file = File::create(&path).expect("No se pudo crear el archivo");
+        let file = File::create(path).expect("No se pudo crear el archivo");
         let mut writer = BufWriter::new(file);
 
+        // Escribir el estado de la simulación
+        match estado {
+            EstadoSimulacion::Completa => {
+                writeln!(writer, "Estado de la simulación: COMPLETA").expect("Error al escribir el estado")
+            }
+            EstadoSimulacion::Incompleta(motivo) => {
+                writeln!(writer, "Estado de la simulación: INCOMPLETA ({})", motivo).expect("Error al escribir el estado")
+            }
+        }
+        writeln!(writer).expect("Error al escribir línea en blanco");
+
         // Escribir la tabla de procesos
         writeln!(writer, "Procesos de la Tanda:").expect("Error al escribir en el archivo");
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
-        writeln!(writer, "| Nombre  | Arribo | Duración | Memoria |").expect("Error al escribir encabezado");
+        writeln!(writer, "| Nombre  | Arribo | Duración | Memoria | Prioridad | Paciencia | Segmentos (KB) | Ráfagas | Cambios de memoria | Objetos").expect("Error al escribir encabezado");
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
 
         for proceso in procesos {
             writeln!(
                 writer,
-                "| {:<7} | {:<6} | {:<8} | {:<7} |",
-                proceso.nombre, proceso.arribo, proceso.duracion, proceso.memoria_requerida
+                "| {:<7} | {:<6} | {:<8} | {:<7} | {:<9} | {:<9} | {} | {} | {} | {}",
+                proceso.nombre,
+                proceso.arribo,
+                proceso.duracion,
+                proceso.memoria_requerida,
+                proceso.prioridad,
+                proceso.paciencia.map_or("-".to_string(), |p| p.to_string()),
+                proceso.descripcion_segmentos(),
+                proceso.descripcion_rafagas(),
+                proceso.descripcion_cambios_memoria(),
+                proceso.descripcion_objetos()
             ).expect("Error al escribir los procesos de la tanda");
         }
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
@@ -55,10 +100,21 @@ impl ManipularArchivo {
         writeln!(writer, "\nConfiguración del Simulador:").expect("Error al escribir encabezado de configuración");
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
         writeln!(writer, "Tamaño de memoria: {} KB", configuracion.tamanio_memoria).expect("Error al escribir configuración");
-        writeln!(writer, "Estrategia de asignación: {:?}", configuracion.estrategia).expect("Error al escribir configuración");
-        writeln!(writer, "Tiempo de selección de partición: {} ms", configuracion.tiempo_seleccion).expect("Error al escribir configuración");
-        writeln!(writer, "Tiempo de carga promedio: {} ms", configuracion.tiempo_carga).expect("Error al escribir configuración");
-        writeln!(writer, "Tiempo de liberación de partición: {} ms", configuracion.tiempo_liberacion).expect("Error al escribir configuración");
+        writeln!(writer, "Modelo de memoria: {:?}", configuracion.modo_memoria).expect("Error al escribir configuración");
+        writeln!(writer, "Estrategia de asignación: {}", configuracion.nombre_estrategia()).expect("Error al escribir configuración");
+        writeln!(writer, "Tiempo de selección de partición: {} unidades de tiempo", configuracion.tiempo_seleccion).expect("Error al escribir configuración");
+        writeln!(writer, "Tiempo de carga promedio: {} unidades de tiempo", configuracion.tiempo_carga).expect("Error al escribir configuración");
+        writeln!(writer, "Tiempo de liberación de partición: {} unidades de tiempo", configuracion.tiempo_liberacion).expect("Error al escribir configuración");
+        writeln!(writer, "Política de admisión: {:?}", configuracion.politica_admision).expect("Error al escribir configuración");
+        writeln!(writer, "Desalojo por prioridad: {}", if configuracion.desalojo_por_prioridad { "Sí" } else { "No" }).expect("Error al escribir configuración");
+        writeln!(writer, "Compactación: {:?}", configuracion.compactacion).expect("Error al escribir configuración");
+        writeln!(writer, "Tiempo de reubicación por KB: {} unidades de tiempo", configuracion.tiempo_reubicacion_kb).expect("Error al escribir configuración");
+        writeln!(writer, "Swapping: {:?}", configuracion.swapping).expect("Error al escribir configuración");
+        writeln!(writer, "Área de swap: {} KB, {} KB por unidad de tiempo", configuracion.tamanio_swap, configuracion.tasa_transferencia_swap).expect("Error al escribir configuración");
+        writeln!(writer, "Residencia mínima antes de volver al swap: {} unidades de CPU", configuracion.residencia_minima_swap).expect("Error al escribir configuración");
+        writeln!(writer, "Planificación de CPU: {:?}", configuracion.planificacion).expect("Error al escribir configuración");
+        writeln!(writer, "Dispositivos de E/S: {}", configuracion.dispositivos_es).expect("Error al escribir configuración");
+        writeln!(writer, "Procesos que nunca entran en memoria: {:?}", configuracion.insatisfacibles).expect("Error al escribir configuración");
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
 
         // Escribir la tabla de eventos
@@ -69,9 +125,40 @@ impl ManipularArchivo {
         }
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
 
-        // Escribir la tabla de resultados
+        // Escribir la tabla de resultados, empezando por el detalle de cada proceso
         writeln!(writer, "\nResultados de la Simulación:").expect("Error al escribir encabezado de resultados");
         writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
+        writeln!(writer, "| Nombre  | Arribo | 1er intento | Asignación | Fin carga | Fin ejecución | Fin liberación | Dirección | Bloque (KB) | Espera | Retorno | Retorno normalizado | Desenlace").expect("Error al escribir encabezado");
+        writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
+        for (proceso, ciclo) in procesos.iter().zip(ciclos_de_vida.iter()) {
+            let celda = |valor: Option<u64>| valor.map_or("-".to_string(), |v| v.to_string());
+            // La espera es el tiempo en el sistema sin ejecutar: retorno menos duración
+            let retorno = ciclo.retorno();
+            let duracion = proceso.get_duracion() as u64;
+            writeln!(
+                writer,
+                "| {:<7} | {:<6} | {:<11} | {:<10} | {:<9} | {:<13} | {:<14} | {:<9} | {:<11} | {:<6} | {:<7} | {:<19} | {}",
+                proceso.nombre,
+                ciclo.arribo,
+                celda(ciclo.primer_intento),
+                celda(ciclo.asignacion),
+                celda(ciclo.fin_carga),
+                celda(ciclo.fin_ejecucion),
+                celda(ciclo.fin_liberacion),
+                celda(ciclo.direccion.map(u64::from)),
+                celda(ciclo.tamanio_bloque.map(u64::from)),
+                celda(retorno.map(|r| r.saturating_sub(duracion))),
+                celda(retorno),
+                retorno.map_or("-".to_string(), |r| format!("{:.2}", r as f64 / duracion as f64)),
+                match ciclo.desenlace {
+                    Desenlace::Terminado => "Terminó".to_string(),
+                    Desenlace::Rechazado => "Rechazado".to_string(),
+                    Desenlace::Abandono(instante) => format!("Abandonó en el tiempo {}", instante),
+                    Desenlace::SinTerminar => "Sin terminar (simulación abortada)".to_string(),
+                }
+            ).expect("Error al escribir el detalle de los procesos");
+        }
+        writeln!(writer, "-------------------------------------------").expect("Error al escribir línea divisora");
         for resultado in resultados {
             writeln!(writer, "{}", resultado).expect("Error al escribir resultado");
         }
@@ -80,4 +167,171 @@ impl ManipularArchivo {
         // Retornar el nombre del archivo creado
         nombre_archivo
     }
+
+    /// Lee una tanda de procesos desde un archivo de texto, con un proceso por línea:
+    /// `Nombre,Arribo,Duracion,Memoria[,Ráfagas[,Cambios[,Prioridad[,Paciencia[,Objetos[,Segmentos]]]]]]`.
+    /// Las ráfagas se separan con `;` y son
+    /// `CPU n` o `ES d n` (n unidades de E/S en el dispositivo d); la primera debe ser de CPU y, si
+    /// hay ráfagas, la duración del proceso es la suma de las de CPU. Los cambios de memoria se
+    /// separan con `;` y son `t +k` o `t -k` (pide o devuelve k KB tras ejecutar t unidades).
+    /// La prioridad es un número donde 0 es la más alta (0 si se omite). La paciencia es el tiempo
+    /// máximo que el proceso espera memoria antes de abandonar (si se omite, espera siempre).
+    /// Los objetos del kernel (modo slab) se separan con `;` y son `tipo cantidad`, como
+    /// `tarea 2; inodo 5`; los segmentos también, como `Código 120; Datos 80; Pila 40`. En los dos
+    /// casos los tamaños tienen que sumar la memoria del proceso.
+    /// Un campo opcional vacío se toma como omitido, y se ignoran las líneas vacías y las que comienzan con `#`.
+    pub fn leer_procesos(ruta: &str) -> Result<Vec<Proceso>, String> {
+        let contenido = std::fs::read_to_string(ruta).map_err(|e| format!("No se pudo leer el archivo {}: {}", ruta, e))?;
+        let mut procesos = Vec::new();
+
+        for (numero, linea) in contenido.lines().enumerate() {
+            let linea = linea.trim();
+            if linea.is_empty() || linea.starts_with('#') {
+                continue;
+            }
+            let error = |detalle: &str| format!("Línea {}: {} ({})", numero + 1, detalle, linea);
+
+            let campos: Vec<&str> = linea.split(',').map(|c| c.trim()).collect();
+            if campos.len() < 4 || campos.len() > 10 {
+                return Err(error("se esperaban los campos Nombre,Arribo,Duracion,Memoria[,Ráfagas[,Cambios[,Prioridad[,Paciencia[,Objetos[,Segmentos]]]]]]"));
+            }
+            let arribo = campos[1].parse::<usize>().map_err(|_| error("arribo inválido"))?;
+            let duracion = campos[2].parse::<usize>().map_err(|_| error("duración inválida"))?;
+            let memoria = match campos[3].parse::<u32>() {
+                Ok(memoria) if memoria > 0 => memoria,
+                _ => return Err(error("la memoria debe ser un número mayor que 0")),
+            };
+
+            let mut proceso = match campos.get(9).filter(|t| !t.is_empty()) {
+                Some(texto) => {
+                    let segmentos = ManipularArchivo::leer_segmentos(texto).map_err(|detalle| error(&detalle))?;
+                    let proceso = Proceso::con_segmentos(campos[0], arribo, duracion, segmentos);
+                    if proceso.get_memoria_requerida() != memoria {
+                        return Err(error(&format!(
+                            "la memoria debe ser la suma de los segmentos ({} KB)",
+                            proceso.get_memoria_requerida()
+                        )));
+                    }
+                    proceso
+                }
+                None => Proceso::new(campos[0], arribo, duracion, memoria),
+            };
+            if let Some(texto) = campos.get(4).filter(|t| !t.is_empty()) {
+                let rafagas = ManipularArchivo::leer_rafagas(texto).map_err(|detalle| error(&detalle))?;
+                proceso = proceso.con_rafagas(rafagas);
+            }
+            if let Some(texto) = campos.get(5).filter(|t| !t.is_empty()) {
+                let cambios = ManipularArchivo::leer_cambios_memoria(texto).map_err(|detalle| error(&detalle))?;
+                proceso = proceso.con_cambios_memoria(cambios);
+            }
+            if let Some(texto) = campos.get(6).filter(|t| !t.is_empty()) {
+                let prioridad = texto.parse::<u32>().map_err(|_| error("prioridad inválida"))?;
+                proceso = proceso.con_prioridad(prioridad);
+            }
+            if let Some(texto) = campos.get(7).filter(|t| !t.is_empty()) {
+                let paciencia = texto.parse::<u64>().map_err(|_| error("paciencia inválida"))?;
+                proceso = proceso.con_paciencia(paciencia);
+            }
+            if let Some(texto) = campos.get(8).filter(|t| !t.is_empty()) {
+                let objetos = ManipularArchivo::leer_objetos(texto).map_err(|detalle| error(&detalle))?;
+                proceso = proceso.con_objetos(objetos);
+                if proceso.get_memoria_requerida() != memoria {
+                    return Err(error(&format!(
+                        "la memoria debe ser la suma de los objetos ({} KB)",
+                        proceso.get_memoria_requerida()
+                    )));
+                }
+            }
+            if proceso.get_duracion() == 0 {
+                return Err(error("la duración debe ser mayor que 0"));
+            }
+            procesos.push(proceso);
+        }
+
+        if procesos.is_empty() {
+            return Err(format!("El archivo {} no contiene procesos", ruta));
+        }
+        // La tanda se simula ordenada por instante de arribo
+        procesos.sort_by_key(|p| p.arribo);
+        Ok(procesos)
+    }
+
+    /// Interpreta una lista de ráfagas como "CPU 3; ES 0 4; CPU 2"
+    fn leer_rafagas(texto: &str) -> Result<Vec<Rafaga>, String> {
+        let mut rafagas = Vec::new();
+        for rafaga in texto.split(';') {
+            let partes: Vec<&str> = rafaga.split_whitespace().collect();
+            let rafaga = match partes.as_slice() {
+                [tipo, duracion] if tipo.eq_ignore_ascii_case("CPU") => match duracion.parse::<u64>() {
+                    Ok(duracion) if duracion > 0 => Rafaga::Cpu(duracion),
+                    _ => return Err("las ráfagas de CPU deben durar más de 0".to_string()),
+                },
+                [tipo, dispositivo, duracion] if tipo.eq_ignore_ascii_case("ES") => {
+                    match (dispositivo.parse::<usize>(), duracion.parse::<u64>()) {
+                        (Ok(dispositivo), Ok(duracion)) => Rafaga::EntradaSalida { dispositivo, duracion },
+                        _ => return Err(format!("ráfaga de E/S inválida: {}", rafaga.trim())),
+                    }
+                }
+                _ => return Err(format!("ráfaga inválida: {}", rafaga.trim())),
+            };
+            rafagas.push(rafaga);
+        }
+        if !matches!(rafagas.first(), Some(Rafaga::Cpu(_))) {
+            return Err("la primera ráfaga debe ser de CPU".to_string());
+        }
+        Ok(rafagas)
+    }
+
+    /// Interpreta una lista de segmentos como "Código 120; Datos 80; Pila 40"
+    fn leer_segmentos(texto: &str) -> Result<Vec<Segmento>, String> {
+        texto
+            .split(';')
+            .map(|segmento| match segmento.trim().rsplit_once(char::is_whitespace) {
+                Some((nombre, tamanio)) if !nombre.trim().is_empty() => match tamanio.parse::<u32>() {
+                    Ok(tamanio) if tamanio > 0 => Ok(Segmento { nombre: nombre.trim().to_string(), tamanio }),
+                    _ => Err(format!("el tamaño de un segmento debe ser mayor que 0: {}", segmento.trim())),
+                },
+                _ => Err(format!("segmento inválido (se espera 'nombre KB'): {}", segmento.trim())),
+            })
+            .collect()
+    }
+
+    /// Interpreta una lista de objetos del kernel como "tarea 2; inodo 5"
+    fn leer_objetos(texto: &str) -> Result<Vec<PedidoObjetos>, String> {
+        texto
+            .split(';')
+            .map(|pedido| {
+                let partes: Vec<&str> = pedido.split_whitespace().collect();
+                match partes.as_slice() {
+                    [tipo, cantidad] => match (TipoObjeto::desde_nombre(tipo), cantidad.parse::<u32>()) {
+                        (Some(tipo), Ok(cantidad)) if cantidad > 0 => Ok(PedidoObjetos { tipo, cantidad }),
+                        (None, _) => Err(format!(
+                            "tipo de objeto desconocido: {} (los tipos son {})",
+                            tipo,
+                            TIPOS_OBJETO.map(|t| t.nombre()).join(", ")
+                        )),
+                        _ => Err(format!("la cantidad de objetos debe ser mayor que 0: {}", pedido.trim())),
+                    },
+                    _ => Err(format!("pedido de objetos inválido (se espera 'tipo cantidad'): {}", pedido.trim())),
+                }
+            })
+            .collect()
+    }
+
+    /// Interpreta una lista de cambios de memoria como "3 +50; 5 -20"
+    fn leer_cambios_memoria(texto: &str) -> Result<Vec<CambioMemoria>, String> {
+        texto
+            .split(';')
+            .map(|cambio| {
+                let partes: Vec<&str> = cambio.split_whitespace().collect();
+                match partes.as_slice() {
+                    [instante, kb] if kb.starts_with(['+', '-']) => match (instante.parse::<u64>(), kb.parse::<i32>()) {
+                        (Ok(tras_ejecutar), Ok(cambio)) => Ok(CambioMemoria { tras_ejecutar, cambio }),
+                        _ => Err(format!("cambio de memoria inválido: {}", cambio.trim())),
+                    },
+                    _ => Err(format!("cambio de memoria inválido (se espera 'instante +KB' o 'instante -KB'): {}", cambio.trim())),
+                }
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,333 @@
+//memoria.rs
+
+//Modelos de memoria que puede usar el simulador. El simulador sólo conoce el trait
+//`ModeloMemoria`; cada modelo decide cómo ubica a los procesos y cómo mide su ocupación.
+
+use crate::compactacion;
+use crate::buddy::MemoriaBuddy;
+use crate::config::Config;
+use crate::estrategias::{EstadisticasBusqueda, EstrategiaAsignacion};
+use crate::paginacion::MemoriaPaginada;
+use crate::particion::{self, EstadoParticion, Particion};
+use crate::particiones_fijas::MemoriaFija;
+use crate::proceso::Proceso;
+use crate::segmentacion::MemoriaSegmentada;
+use crate::segregada::MemoriaSegregada;
+use crate::slab::MemoriaSlab;
+
+/// Modelo de memoria elegido para la simulación
+#[derive(Debug, Clone)]
+pub enum ModoMemoria {
+    ParticionesDinamicas,         // Particiones de tamaño variable (MVT)
+    ParticionesFijas(Vec<u32>),   // Tabla de particiones de tamaño fijo, en KB (MFT)
+    Buddy { orden_minimo: u32 },  // Sistema buddy binario; el bloque más chico mide 2^orden_minimo KB
+    Paginacion { tamanio_pagina: u32 }, // Paginación con marcos del tamaño de una página, en KB
+    Segmentacion,                 // Cada segmento del proceso en su propia partición dinámica
+    Segregada { tamanios_rapidos: Vec<u32>, limite_general: u32 }, // Listas de bloques libres por tamaño
+    Slab { orden_slab: u32, recuperar_vacios: bool }, // Caches de objetos en slabs de 2^orden_slab KB sobre el buddy
+}
+
+/// Lugar de la memoria que se le asignó a un proceso
+#[derive(Debug, Clone)]
+pub struct Ubicacion {
+    pub direccion: u32, // Dirección de comienzo del bloque
+    pub tamanio: u32,   // KB reservados para el proceso (puede superar lo que pidió)
+}
+
+/// Resultado de cambiar el tamaño de la memoria de un proceso que ya está en memoria
+#[derive(Debug, Clone)]
+pub enum Redimension {
+    EnLugar,              // El bloque cambió de tamaño sin moverse
+    Reubicado(Ubicacion), // El bloque se movió a otro lugar para poder crecer
+    SinLugar,             // El nuevo tamaño no entra; el proceso conserva el anterior
+}
+
+/// Operaciones que el simulador necesita de un modelo de memoria
+pub trait ModeloMemoria {
+    /// Intenta ubicar al proceso. Si `tiempo_carga` es mayor que cero, su memoria queda
+    /// reservada (Cargando) hasta que termine la selección y la carga.
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion>;
+
+    /// Cambia el estado de la memoria de un proceso (fin de carga, comienzo de liberación)
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion);
+
+    /// Lleva la memoria de un proceso residente al tamaño que indica `proceso`
+    /// (que ya tiene su nuevo tamaño y, si corresponde, sus nuevos segmentos)
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension;
+
+    /// Devuelve la memoria del proceso al estado libre
+    fn liberar(&mut self, nombre_proceso: &str);
+
+    /// Memoria total administrada por el modelo, en KB
+    fn tamanio_total(&self) -> u32;
+
+    /// Mayor cantidad de memoria que el modelo puede darle a un solo proceso con la memoria vacía, en KB.
+    /// Un proceso que pide más nunca va a poder cargarse.
+    fn maximo_asignable(&self) -> u32 {
+        self.tamanio_total()
+    }
+
+    /// Indica si el proceso entraría con la memoria vacía; si no, devuelve el motivo
+    /// (ej: "el modelo de memoria puede darle a un proceso a lo sumo 512 KB")
+    fn cabe_con_memoria_vacia(&self, proceso: &Proceso) -> Result<(), String> {
+        if proceso.get_memoria_requerida() <= self.maximo_asignable() {
+            Ok(())
+        } else {
+            Err(format!(
+                "el modelo de memoria puede darle a un proceso a lo sumo {} KB",
+                self.maximo_asignable()
+            ))
+        }
+    }
+
+    /// Memoria reservada por procesos, en KB
+    fn memoria_ocupada(&self) -> u32;
+
+    /// Memoria libre, en KB
+    fn memoria_libre(&self) -> u32 {
+        self.tamanio_total() - self.memoria_ocupada()
+    }
+
+    /// Tamaño de cada hueco libre, en orden de dirección
+    fn huecos(&self) -> Vec<u32>;
+
+    /// Compacta la memoria si el modelo lo permite. Devuelve los KB movidos.
+    fn compactar(&mut self) -> u32 {
+        0
+    }
+
+    /// Avisa al modelo que transcurrieron `duracion` unidades de tiempo sin cambios,
+    /// para que acumule sus métricas ponderadas por tiempo
+    fn avanzar_tiempo(&mut self, _duracion: u64) {}
+
+    /// Devuelve y descarta los mensajes que el modelo quiere dejar en el registro de eventos
+    /// (por ejemplo, divisiones y combinaciones de bloques)
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Estado actual de la memoria en una línea, para acompañar los eventos que la modifican
+    fn mapa(&self) -> Option<String> {
+        None
+    }
+
+    /// Búsquedas de hueco que hizo la estrategia de asignación, si el modelo usa una
+    fn estadisticas_busqueda(&self) -> Option<EstadisticasBusqueda> {
+        None
+    }
+
+    /// Resultados propios del modelo para el informe final
+    fn resumen(&self, _tiempo_total: u64) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Crea el modelo de memoria elegido en la configuración
+pub fn crear_memoria(configuracion: &Config) -> Box<dyn ModeloMemoria> {
+    match &configuracion.modo_memoria {
+        ModoMemoria::ParticionesDinamicas => Box::new(MemoriaDinamica::nueva(
+            configuracion.tamanio_memoria,
+            configuracion.estrategia.clonar(),
+        )),
+        ModoMemoria::ParticionesFijas(tamanios) => Box::new(MemoriaFija::nueva(
+            tamanios,
+            configuracion.estrategia.clonar(),
+        )),
+        ModoMemoria::Buddy { orden_minimo } => Box::new(MemoriaBuddy::nueva(
+            configuracion.tamanio_memoria,
+            *orden_minimo,
+        )),
+        ModoMemoria::Paginacion { tamanio_pagina } => Box::new(MemoriaPaginada::nueva(
+            configuracion.tamanio_memoria,
+            *tamanio_pagina,
+        )),
+        ModoMemoria::Segmentacion => Box::new(MemoriaSegmentada::nueva(
+            configuracion.tamanio_memoria,
+            configuracion.estrategia.clonar(),
+        )),
+        ModoMemoria::Segregada { tamanios_rapidos, limite_general } => Box::new(MemoriaSegregada::nueva(
+            configuracion.tamanio_memoria,
+            tamanios_rapidos,
+            *limite_general,
+        )),
+        ModoMemoria::Slab { orden_slab, recuperar_vacios } => Box::new(MemoriaSlab::nueva(
+            configuracion.tamanio_memoria,
+            *orden_slab,
+            *recuperar_vacios,
+        )),
+    }
+}
+
+/// Particiones dinámicas: la memoria es una lista de particiones de tamaño variable
+/// que se dividen al asignar y se combinan al liberar.
+pub struct MemoriaDinamica {
+    particiones: Vec<Particion>,
+    estrategia: Box<dyn EstrategiaAsignacion>,
+    ultima_asignada: usize, // Dirección donde terminó la última asignación (Next-fit)
+    tamanio_memoria: u32,
+    busquedas: EstadisticasBusqueda,
+}
+
+impl MemoriaDinamica {
+    /// Al comenzar, toda la memoria es un único hueco libre del tamaño especificado.
+    pub fn nueva(tamanio_memoria: u32, estrategia: Box<dyn EstrategiaAsignacion>) -> MemoriaDinamica {
+        MemoriaDinamica {
+            particiones: vec![Particion::nueva(0, tamanio_memoria)],
+            estrategia,
+            ultima_asignada: 0,
+            tamanio_memoria,
+            busquedas: EstadisticasBusqueda::default(),
+        }
+    }
+}
+
+impl ModeloMemoria for MemoriaDinamica {
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let direccion = self.estrategia.asignar_proceso(
+            &mut self.particiones,
+            proceso,
+            &mut self.ultima_asignada,
+            tiempo_carga,
+            &mut self.busquedas,
+        )?;
+        self.estrategia.asignacion_confirmada();
+        Some(Ubicacion {
+            direccion,
+            tamanio: proceso.get_memoria_requerida(),
+        })
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.estado = estado.clone();
+            }
+        }
+    }
+
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let Some(direccion) = self
+            .particiones
+            .iter()
+            .find(|p| p.id_proceso.as_deref() == Some(proceso.get_nombre()))
+            .map(|p| p.direccion_comienzo)
+        else {
+            return Redimension::SinLugar;
+        };
+        redimensionar_bloque(
+            &mut self.particiones,
+            direccion,
+            proceso,
+            self.estrategia.as_mut(),
+            &mut self.ultima_asignada,
+            &mut self.busquedas,
+        )
+    }
+
+    /// La partición del proceso vuelve a estar libre y se combina con los huecos vecinos.
+    fn liberar(&mut self, nombre_proceso: &str) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.liberar();
+                self.estrategia.hueco_liberado(particion.direccion_comienzo);
+            }
+        }
+        particion::combinar_particiones(&mut self.particiones);
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.tamanio_memoria
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.particiones.iter().filter(|p| !p.esta_libre()).map(|p| p.tamanio).sum()
+    }
+
+    fn huecos(&self) -> Vec<u32> {
+        self.particiones.iter().filter(|p| p.esta_libre()).map(|p| p.tamanio).collect()
+    }
+
+    /// Tras compactar, Next-fit sigue buscando desde el comienzo del único hueco.
+    fn compactar(&mut self) -> u32 {
+        let kb_movidos = compactacion::compactar(&mut self.particiones);
+        self.ultima_asignada = (self.memoria_ocupada() % self.tamanio_memoria) as usize;
+        kb_movidos
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        self.estrategia.avanzar_tiempo(duracion);
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        self.estrategia.tomar_mensajes()
+    }
+
+    fn estadisticas_busqueda(&self) -> Option<EstadisticasBusqueda> {
+        Some(self.busquedas)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        self.estrategia.resumen(tiempo_total)
+    }
+}
+
+/// Cambia el tamaño del bloque ocupado que comienza en `direccion` al de `bloque`.
+/// Si se achica, o si el hueco siguiente alcanza para crecer, cambia en el lugar; si no, la
+/// estrategia busca otro hueco (con el bloque actual todavía ocupado) y el bloque se mueve allí.
+pub fn redimensionar_bloque(
+    particiones: &mut Vec<Particion>,
+    direccion: u32,
+    bloque: &Proceso,
+    estrategia: &mut dyn EstrategiaAsignacion,
+    ultima_asignada: &mut usize,
+    busquedas: &mut EstadisticasBusqueda,
+) -> Redimension {
+    let Some(indice) = particiones.iter().position(|p| p.direccion_comienzo == direccion && !p.esta_libre()) else {
+        return Redimension::SinLugar;
+    };
+    let nuevo = bloque.get_memoria_requerida();
+    let actual = particiones[indice].tamanio;
+
+    // Al achicarse, el sobrante se suma al hueco siguiente (o forma uno nuevo)
+    if nuevo <= actual {
+        if nuevo < actual {
+            particiones[indice].tamanio = nuevo;
+            particiones.insert(indice + 1, Particion::nueva(direccion + nuevo, actual - nuevo));
+            particion::combinar_particiones(particiones);
+        }
+        return Redimension::EnLugar;
+    }
+
+    // Crece en el lugar tomando el comienzo del hueco siguiente
+    let falta = nuevo - actual;
+    if particiones.get(indice + 1).is_some_and(|p| p.esta_libre() && p.tamanio >= falta) {
+        particiones[indice].tamanio = nuevo;
+        if particiones[indice + 1].tamanio == falta {
+            particiones.remove(indice + 1);
+        } else {
+            particiones[indice + 1].direccion_comienzo += falta;
+            particiones[indice + 1].tamanio -= falta;
+        }
+        return Redimension::EnLugar;
+    }
+
+    // Se busca otro hueco para el bloque completo y se libera el anterior
+    let estado = particiones[indice].estado.clone();
+    let Some(nueva_direccion) = estrategia.asignar_proceso(particiones, bloque, ultima_asignada, 0, busquedas) else {
+        return Redimension::SinLugar;
+    };
+    estrategia.asignacion_confirmada();
+    estrategia.hueco_liberado(direccion);
+    for particion in particiones.iter_mut() {
+        if particion.direccion_comienzo == direccion && !particion.esta_libre() {
+            particion.liberar();
+        } else if particion.direccion_comienzo == nueva_direccion && !particion.esta_libre() {
+            particion.estado = estado.clone();
+        }
+    }
+    particion::combinar_particiones(particiones);
+    Redimension::Reubicado(Ubicacion {
+        direccion: nueva_direccion,
+        tamanio: nuevo,
+    })
+}
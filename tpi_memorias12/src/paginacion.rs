@@ -0,0 +1,307 @@
+//paginacion.rs
+
+//Paginación: la memoria se divide en marcos del tamaño de una página y cada proceso recibe
+//una tabla de páginas que ubica cada una de sus páginas en un marco cualquiera. Como los marcos
+//no necesitan ser contiguos, no hay fragmentación externa; lo que se pierde es el sobrante de la
+//última página de cada proceso y el espacio que ocupan las tablas de páginas.
+
+use crate::memoria::{ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::EstadoParticion;
+use crate::proceso::Proceso;
+
+/// Bytes que ocupa cada entrada de una tabla de páginas
+const TAMANIO_ENTRADA_TABLA: u32 = 4;
+
+/// Tabla de páginas de un proceso en memoria
+struct TablaPaginas {
+    id_proceso: String,
+    marcos: Vec<u32>, // Marco asignado a cada página, en orden de página
+    memoria_requerida: u32,
+    estado: EstadoParticion,
+}
+
+/// Lo que ocupó cada proceso que pasó por la memoria, para el informe final
+struct RegistroProceso {
+    id_proceso: String,
+    paginas: u32,
+    desperdicio: u32, // KB sin usar de la última página
+}
+
+pub struct MemoriaPaginada {
+    tamanio_pagina: u32,
+    marcos: Vec<Option<String>>, // Tabla de marcos: proceso dueño de cada marco (None si está libre)
+    tablas: Vec<TablaPaginas>,
+    historial: Vec<RegistroProceso>,
+    tamanio_memoria: u32,
+    mensajes: Vec<String>,
+    desperdicio_acumulado: u64, // KB de fragmentación interna por unidad de tiempo
+    asignado_acumulado: u64, // KB en marcos ocupados por unidad de tiempo
+    entradas_acumuladas: u64, // Entradas de tablas de páginas en memoria por unidad de tiempo
+    maximo_marcos_ocupados: u32,
+}
+
+impl MemoriaPaginada {
+    /// La memoria se divide en tantos marcos enteros como entren; el resto no se usa.
+    pub fn nueva(tamanio_memoria: u32, tamanio_pagina: u32) -> MemoriaPaginada {
+        MemoriaPaginada {
+            tamanio_pagina,
+            marcos: vec![None; (tamanio_memoria / tamanio_pagina) as usize],
+            tablas: Vec::new(),
+            historial: Vec::new(),
+            tamanio_memoria,
+            mensajes: Vec::new(),
+            desperdicio_acumulado: 0,
+            asignado_acumulado: 0,
+            entradas_acumuladas: 0,
+            maximo_marcos_ocupados: 0,
+        }
+    }
+
+    fn marcos_ocupados(&self) -> u32 {
+        self.marcos.iter().filter(|m| m.is_some()).count() as u32
+    }
+
+    /// Fragmentación interna actual (sobrante de las últimas páginas), en KB
+    fn fragmentacion_interna(&self) -> u32 {
+        self.tablas
+            .iter()
+            .map(|t| t.marcos.len() as u32 * self.tamanio_pagina - t.memoria_requerida)
+            .sum()
+    }
+
+    /// Entradas de tablas de páginas de los procesos en memoria
+    fn entradas_en_memoria(&self) -> u32 {
+        self.tablas.iter().map(|t| t.marcos.len() as u32).sum()
+    }
+}
+
+impl ModeloMemoria for MemoriaPaginada {
+    /// Cada página del proceso va al marco libre de menor número, sin importar si son contiguos.
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let memoria_requerida = proceso.get_memoria_requerida();
+        let paginas = memoria_requerida.div_ceil(self.tamanio_pagina).max(1);
+        let libres: Vec<u32> = self
+            .marcos
+            .iter()
+            .enumerate()
+            .filter(|(_, dueno)| dueno.is_none())
+            .map(|(marco, _)| marco as u32)
+            .take(paginas as usize)
+            .collect();
+        if (libres.len() as u32) < paginas {
+            return None;
+        }
+
+        for &marco in &libres {
+            self.marcos[marco as usize] = Some(proceso.nombre.clone());
+        }
+        self.maximo_marcos_ocupados = self.maximo_marcos_ocupados.max(self.marcos_ocupados());
+
+        let desperdicio = paginas * self.tamanio_pagina - memoria_requerida;
+        let entradas: Vec<String> = libres
+            .iter()
+            .enumerate()
+            .map(|(pagina, marco)| format!("{}→{}", pagina, marco))
+            .collect();
+        self.mensajes.push(format!(
+            "se creó la tabla de páginas del proceso {} ({} páginas, {} bytes; página→marco: {}). Sobran {} KB en su última página",
+            proceso.nombre,
+            paginas,
+            paginas * TAMANIO_ENTRADA_TABLA,
+            entradas.join(", "),
+            desperdicio
+        ));
+        self.historial.push(RegistroProceso {
+            id_proceso: proceso.nombre.clone(),
+            paginas,
+            desperdicio,
+        });
+        self.tablas.push(TablaPaginas {
+            id_proceso: proceso.nombre.clone(),
+            marcos: libres.clone(),
+            memoria_requerida,
+            estado: if tiempo_carga > 0 {
+                EstadoParticion::Cargando
+            } else {
+                EstadoParticion::Ocupada
+            },
+        });
+
+        Some(Ubicacion {
+            direccion: libres[0] * self.tamanio_pagina,
+            tamanio: paginas * self.tamanio_pagina,
+        })
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for tabla in self.tablas.iter_mut() {
+            if tabla.id_proceso == nombre_proceso {
+                tabla.estado = estado.clone();
+            }
+        }
+    }
+
+    /// La tabla de páginas gana o pierde páginas al final; como cualquier marco libre sirve,
+    /// el proceso nunca se mueve: sólo falta lugar si no quedan marcos libres suficientes.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let memoria_requerida = proceso.get_memoria_requerida();
+        let paginas = memoria_requerida.div_ceil(self.tamanio_pagina).max(1) as usize;
+        let Some(posicion) = self.tablas.iter().position(|t| t.id_proceso == proceso.nombre) else {
+            return Redimension::SinLugar;
+        };
+        let actuales = self.tablas[posicion].marcos.len();
+
+        if paginas > actuales {
+            let libres: Vec<u32> = self
+                .marcos
+                .iter()
+                .enumerate()
+                .filter(|(_, dueno)| dueno.is_none())
+                .map(|(marco, _)| marco as u32)
+                .take(paginas - actuales)
+                .collect();
+            if libres.len() < paginas - actuales {
+                return Redimension::SinLugar;
+            }
+            for &marco in &libres {
+                self.marcos[marco as usize] = Some(proceso.nombre.clone());
+            }
+            self.maximo_marcos_ocupados = self.maximo_marcos_ocupados.max(self.marcos_ocupados());
+            self.mensajes.push(format!(
+                "la tabla de páginas del proceso {} crece a {} páginas (marcos nuevos: {})",
+                proceso.nombre,
+                paginas,
+                libres.iter().map(|m| m.to_string()).collect::<Vec<String>>().join(", ")
+            ));
+            self.tablas[posicion].marcos.extend(libres);
+        } else if paginas < actuales {
+            let liberados = self.tablas[posicion].marcos.split_off(paginas);
+            for &marco in &liberados {
+                self.marcos[marco as usize] = None;
+            }
+            self.mensajes.push(format!(
+                "la tabla de páginas del proceso {} se reduce a {} páginas (se liberan los marcos: {})",
+                proceso.nombre,
+                paginas,
+                liberados.iter().map(|m| m.to_string()).collect::<Vec<String>>().join(", ")
+            ));
+        }
+
+        self.tablas[posicion].memoria_requerida = memoria_requerida;
+        if let Some(registro) = self.historial.iter_mut().rev().find(|r| r.id_proceso == proceso.nombre) {
+            registro.paginas = paginas as u32;
+            registro.desperdicio = paginas as u32 * self.tamanio_pagina - memoria_requerida;
+        }
+        Redimension::EnLugar
+    }
+
+    /// Los marcos del proceso quedan libres y su tabla de páginas se descarta
+    fn liberar(&mut self, nombre_proceso: &str) {
+        for marco in self.marcos.iter_mut() {
+            if marco.as_deref() == Some(nombre_proceso) {
+                *marco = None;
+            }
+        }
+        self.tablas.retain(|t| t.id_proceso != nombre_proceso);
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.marcos.len() as u32 * self.tamanio_pagina
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.marcos_ocupados() * self.tamanio_pagina
+    }
+
+    /// Cualquier marco libre sirve para cualquier página, así que toda la memoria libre
+    /// se comporta como un único hueco
+    fn huecos(&self) -> Vec<u32> {
+        match self.memoria_libre() {
+            0 => Vec::new(),
+            libre => vec![libre],
+        }
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        self.desperdicio_acumulado += self.fragmentacion_interna() as u64 * duracion;
+        self.asignado_acumulado += self.memoria_ocupada() as u64 * duracion;
+        self.entradas_acumuladas += self.entradas_en_memoria() as u64 * duracion;
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.mensajes)
+    }
+
+    /// Tabla de marcos agrupada en tramos consecutivos con el mismo dueño
+    fn mapa(&self) -> Option<String> {
+        let mut tramos: Vec<String> = Vec::new();
+        let mut comienzo = 0;
+        for marco in 1..=self.marcos.len() {
+            if marco == self.marcos.len() || self.marcos[marco] != self.marcos[comienzo] {
+                let dueno = self.marcos[comienzo].as_deref().unwrap_or("libre");
+                if marco - comienzo == 1 {
+                    tramos.push(format!("[{}] {}", comienzo, dueno));
+                } else {
+                    tramos.push(format!("[{}-{}] {}", comienzo, marco - 1, dueno));
+                }
+                comienzo = marco;
+            }
+        }
+        Some(format!("Mapa de marcos: {}", tramos.join(" | ")))
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = Vec::new();
+        let sin_usar = self.tamanio_memoria - self.tamanio_total();
+        resumen.push(format!(
+            "Paginación: páginas de {} KB, {} marcos{}. Máximo de marcos ocupados a la vez: {}.",
+            self.tamanio_pagina,
+            self.marcos.len(),
+            if sin_usar > 0 {
+                format!(" ({} KB sin usar por no completar un marco)", sin_usar)
+            } else {
+                String::new()
+            },
+            self.maximo_marcos_ocupados
+        ));
+
+        for registro in &self.historial {
+            resumen.push(format!(
+                "Proceso {}: {} páginas, tabla de páginas de {} bytes, {} KB sin usar en la última página.",
+                registro.id_proceso,
+                registro.paginas,
+                registro.paginas * TAMANIO_ENTRADA_TABLA,
+                registro.desperdicio
+            ));
+        }
+
+        // Promedios ponderados por tiempo sobre toda la simulación
+        let desperdicio_medio = if tiempo_total > 0 {
+            self.desperdicio_acumulado as f64 / tiempo_total as f64
+        } else {
+            0.0
+        };
+        let porcentaje = if self.asignado_acumulado > 0 {
+            self.desperdicio_acumulado as f64 * 100.0 / self.asignado_acumulado as f64
+        } else {
+            0.0
+        };
+        resumen.push(format!(
+            "Fragmentación interna media (última página): {:.2} KB ({:.2}% de la memoria asignada a procesos).",
+            desperdicio_medio,
+            porcentaje
+        ));
+
+        let entradas_medias = if tiempo_total > 0 {
+            self.entradas_acumuladas as f64 / tiempo_total as f64
+        } else {
+            0.0
+        };
+        resumen.push(format!(
+            "Sobrecarga media de tablas de páginas: {:.2} bytes ({} bytes por entrada).",
+            entradas_medias * TAMANIO_ENTRADA_TABLA as f64,
+            TAMANIO_ENTRADA_TABLA
+        ));
+        resumen
+    }
+}
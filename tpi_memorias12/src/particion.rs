@@ -1,39 +1,46 @@
 //particion.rs
 
-//Todas las particiones miden 1kb.
+//La memoria es una lista de particiones de tamaño variable, ordenada por dirección.
+//Las particiones libres (huecos) se dividen al asignar y se combinan al liberar.
 
 #[derive(Debug, Clone)]
 pub struct Particion {
+    pub direccion_comienzo: u32, // Dirección (en KB) donde comienza la partición
+    pub tamanio: u32, // Tamaño de la partición en KB
     pub id_proceso: Option<String>, // Será None si la partición está libre
     pub tiempo_de_arribo: Option<u32>, // Será None si la partición está libre
-    pub tiempo_de_vida: Option<u32>, // Será None inicialmente y se asignará cuando se ocupe
     pub estado: EstadoParticion,
 }
 
 impl Particion {
     /// Crea una nueva partición libre
-    pub fn nueva() -> Particion {
+    pub fn nueva(direccion_comienzo: u32, tamanio: u32) -> Particion {
         Particion {
+            direccion_comienzo,
+            tamanio,
             id_proceso: None, // Libre por defecto
             tiempo_de_arribo: None, // Ningún proceso asignado inicialmente
-            tiempo_de_vida: None, // Ningún proceso asignado inicialmente
             estado: EstadoParticion::Libre,
         }
     }
 
     /// Ocupar la partición con un proceso específico
-    pub fn ocupar(&mut self, nombre_proceso: String, tiempo_arribo: u32, tiempo_vida: u32) {
+    /// Si `tiempo_carga` es mayor que cero, la partición queda reservada (Cargando)
+    /// hasta que termine la selección y la carga del proceso.
+    pub fn ocupar(&mut self, nombre_proceso: String, tiempo_arribo: u32, tiempo_carga: u32) {
         self.id_proceso = Some(nombre_proceso);
         self.tiempo_de_arribo = Some(tiempo_arribo);
-        self.tiempo_de_vida = Some(tiempo_vida);
-        self.estado = EstadoParticion::Ocupada;
+        self.estado = if tiempo_carga > 0 {
+            EstadoParticion::Cargando
+        } else {
+            EstadoParticion::Ocupada
+        };
     }
 
     /// Liberar la partición
     pub fn liberar(&mut self) {
         self.id_proceso = None;
         self.tiempo_de_arribo = None;
-        self.tiempo_de_vida = None;
         self.estado = EstadoParticion::Libre;
     }
 
@@ -41,11 +48,66 @@ impl Particion {
     pub fn esta_libre(&self) -> bool {
         matches!(self.estado, EstadoParticion::Libre)
     }
+
+    /// Dirección siguiente al último KB de la partición
+    pub fn direccion_fin(&self) -> u32 {
+        self.direccion_comienzo + self.tamanio
+    }
 }
 
-/// Define los estados posibles de una partición: libre o ocupada
+/// Define los estados posibles de una partición:
+/// libre, cargando un proceso, ocupada por un proceso en ejecución o en liberación.
 #[derive(Debug, Clone)]
 pub enum EstadoParticion {
     Libre,
+    Cargando,
     Ocupada,
-}
\ No newline at end of file
+    Liberando,
+}
+
+/// Ocupa `tamanio` KB del hueco `indice` a partir de `direccion`.
+/// El hueco se divide: lo que sobra antes y después de la asignación queda como particiones libres.
+/// Devuelve el índice de la partición ocupada.
+pub fn dividir_hueco(
+    particiones: &mut Vec<Particion>,
+    indice: usize,
+    direccion: u32,
+    tamanio: u32,
+) -> usize {
+    let hueco = particiones[indice].clone();
+    let mut indice_asignado = indice;
+
+    // Parte libre que queda antes de la dirección asignada
+    if direccion > hueco.direccion_comienzo {
+        particiones[indice].tamanio = direccion - hueco.direccion_comienzo;
+        particiones.insert(indice + 1, Particion::nueva(direccion, tamanio));
+        indice_asignado = indice + 1;
+    } else {
+        particiones[indice].tamanio = tamanio;
+    }
+
+    // Parte libre que queda después del bloque asignado
+    let fin_asignado = direccion + tamanio;
+    if fin_asignado < hueco.direccion_fin() {
+        particiones.insert(
+            indice_asignado + 1,
+            Particion::nueva(fin_asignado, hueco.direccion_fin() - fin_asignado),
+        );
+    }
+
+    indice_asignado
+}
+
+/// Combina particiones libres adyacentes para que cada hueco sea una única partición.
+pub fn combinar_particiones(particiones: &mut Vec<Particion>) {
+    let mut i = 0;
+    while i + 1 < particiones.len() {
+        if particiones[i].esta_libre() && particiones[i + 1].esta_libre() {
+            particiones[i].tamanio += particiones[i + 1].tamanio;
+            particiones.remove(i + 1);
+            // No incrementamos i para verificar si la siguiente partición también está libre
+        } else {
+            i += 1;
+        }
+    }
+}
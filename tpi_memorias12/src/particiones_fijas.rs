@@ -0,0 +1,221 @@
+//particiones_fijas.rs
+
+//Particiones fijas (MFT): la memoria se divide de antemano en una tabla de particiones
+//que nunca cambian de tamaño. Cada proceso ocupa una partición entera, y lo que sobra
+//dentro de ella es fragmentación interna.
+
+use crate::estrategias::{EstadisticasBusqueda, EstrategiaAsignacion};
+use crate::memoria::{ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::{EstadoParticion, Particion};
+use crate::proceso::Proceso;
+
+pub struct MemoriaFija {
+    particiones: Vec<Particion>,
+    estrategia: Box<dyn EstrategiaAsignacion>, // Regla para elegir entre las particiones libres que alcanzan
+    ultima_asignada: u32, // Dirección donde terminó la última asignación (Next-fit)
+    memoria_utilizada: Vec<u32>, // KB que usa el proceso alojado en cada partición (0 si está libre)
+    asignaciones: Vec<u32>, // Cantidad de procesos que pasaron por cada partición
+    desperdicio_acumulado: Vec<u64>, // KB de fragmentación interna por unidad de tiempo, por partición
+    tiempo_ocupada: Vec<u64>, // Tiempo que cada partición estuvo ocupada
+    busquedas: EstadisticasBusqueda,
+}
+
+impl MemoriaFija {
+    /// Crea la tabla de particiones fijas, contiguas desde la dirección 0
+    pub fn nueva(tamanios: &[u32], estrategia: Box<dyn EstrategiaAsignacion>) -> MemoriaFija {
+        let mut particiones = Vec::new();
+        let mut direccion = 0;
+        for &tamanio in tamanios {
+            particiones.push(Particion::nueva(direccion, tamanio));
+            direccion += tamanio;
+        }
+
+        let cantidad = particiones.len();
+        MemoriaFija {
+            particiones,
+            estrategia,
+            ultima_asignada: 0,
+            memoria_utilizada: vec![0; cantidad],
+            asignaciones: vec![0; cantidad],
+            desperdicio_acumulado: vec![0; cantidad],
+            tiempo_ocupada: vec![0; cantidad],
+            busquedas: EstadisticasBusqueda::default(),
+        }
+    }
+
+    /// Fragmentación interna actual, en KB
+    fn fragmentacion_interna(&self) -> u32 {
+        self.particiones
+            .iter()
+            .zip(self.memoria_utilizada.iter())
+            .filter(|(p, _)| !p.esta_libre())
+            .map(|(p, utilizada)| p.tamanio - utilizada)
+            .sum()
+    }
+}
+
+impl ModeloMemoria for MemoriaFija {
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let (indice, _) = self.estrategia.buscar_hueco(&self.particiones, proceso, self.ultima_asignada, &mut self.busquedas)?;
+
+        // El proceso ocupa la partición entera, sin dividirla
+        let tamanio_total = self.tamanio_total();
+        let particion = &mut self.particiones[indice];
+        particion.ocupar(proceso.nombre.clone(), proceso.arribo as u32, tiempo_carga);
+        self.memoria_utilizada[indice] = proceso.get_memoria_requerida();
+        self.asignaciones[indice] += 1;
+        self.ultima_asignada = particion.direccion_fin() % tamanio_total;
+        self.estrategia.asignacion_confirmada();
+
+        let particion = &self.particiones[indice];
+        Some(Ubicacion {
+            direccion: particion.direccion_comienzo,
+            tamanio: particion.tamanio,
+        })
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.estado = estado.clone();
+            }
+        }
+    }
+
+    /// Mientras el proceso entre en su partición cambia en el lugar; si la supera, se mueve a
+    /// otra partición libre que alcance, elegida por la estrategia.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let Some(actual) = self
+            .particiones
+            .iter()
+            .position(|p| p.id_proceso.as_deref() == Some(proceso.get_nombre()))
+        else {
+            return Redimension::SinLugar;
+        };
+        if proceso.get_memoria_requerida() <= self.particiones[actual].tamanio {
+            self.memoria_utilizada[actual] = proceso.get_memoria_requerida();
+            return Redimension::EnLugar;
+        }
+
+        let Some((indice, _)) = self.estrategia.buscar_hueco(&self.particiones, proceso, self.ultima_asignada, &mut self.busquedas) else {
+            return Redimension::SinLugar;
+        };
+        let estado = self.particiones[actual].estado.clone();
+        self.particiones[actual].liberar();
+        self.memoria_utilizada[actual] = 0;
+        self.estrategia.asignacion_confirmada();
+        self.estrategia.hueco_liberado(self.particiones[actual].direccion_comienzo);
+
+        let tamanio_total = self.tamanio_total();
+        let particion = &mut self.particiones[indice];
+        particion.ocupar(proceso.nombre.clone(), proceso.arribo as u32, 0);
+        particion.estado = estado;
+        self.memoria_utilizada[indice] = proceso.get_memoria_requerida();
+        self.asignaciones[indice] += 1;
+        self.ultima_asignada = particion.direccion_fin() % tamanio_total;
+        Redimension::Reubicado(Ubicacion {
+            direccion: particion.direccion_comienzo,
+            tamanio: particion.tamanio,
+        })
+    }
+
+    fn liberar(&mut self, nombre_proceso: &str) {
+        for (indice, particion) in self.particiones.iter_mut().enumerate() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.liberar();
+                self.memoria_utilizada[indice] = 0;
+                self.estrategia.hueco_liberado(particion.direccion_comienzo);
+            }
+        }
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.particiones.iter().map(|p| p.tamanio).sum()
+    }
+
+    /// Un proceso nunca ocupa más de una partición
+    fn maximo_asignable(&self) -> u32 {
+        self.particiones.iter().map(|p| p.tamanio).max().unwrap_or(0)
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.particiones.iter().filter(|p| !p.esta_libre()).map(|p| p.tamanio).sum()
+    }
+
+    /// Cada partición libre es un hueco: las particiones fijas nunca se combinan
+    fn huecos(&self) -> Vec<u32> {
+        self.particiones.iter().filter(|p| p.esta_libre()).map(|p| p.tamanio).collect()
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        for (indice, particion) in self.particiones.iter().enumerate() {
+            if !particion.esta_libre() {
+                let desperdicio = (particion.tamanio - self.memoria_utilizada[indice]) as u64;
+                self.desperdicio_acumulado[indice] += desperdicio * duracion;
+                self.tiempo_ocupada[indice] += duracion;
+            }
+        }
+        self.estrategia.avanzar_tiempo(duracion);
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        self.estrategia.tomar_mensajes()
+    }
+
+    fn estadisticas_busqueda(&self) -> Option<EstadisticasBusqueda> {
+        Some(self.busquedas)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = Vec::new();
+        resumen.push(format!(
+            "Particiones fijas: {} particiones, {} KB en total. Fragmentación interna al finalizar: {} KB.",
+            self.particiones.len(),
+            self.tamanio_total(),
+            self.fragmentacion_interna()
+        ));
+
+        for (indice, particion) in self.particiones.iter().enumerate() {
+            let desperdicio_medio = if self.tiempo_ocupada[indice] > 0 {
+                self.desperdicio_acumulado[indice] as f64 / self.tiempo_ocupada[indice] as f64
+            } else {
+                0.0
+            };
+            resumen.push(format!(
+                "Partición {} (dirección {}, {} KB): {} asignaciones, ocupada {} unidades de tiempo, fragmentación interna media {:.2} KB.",
+                indice + 1,
+                particion.direccion_comienzo,
+                particion.tamanio,
+                self.asignaciones[indice],
+                self.tiempo_ocupada[indice],
+                desperdicio_medio
+            ));
+        }
+
+        // Promedios ponderados por tiempo sobre toda la simulación
+        let desperdicio_total: u64 = self.desperdicio_acumulado.iter().sum();
+        let asignado_total: u64 = self
+            .particiones
+            .iter()
+            .zip(self.tiempo_ocupada.iter())
+            .map(|(p, &tiempo)| p.tamanio as u64 * tiempo)
+            .sum();
+        let desperdicio_medio = if tiempo_total > 0 {
+            desperdicio_total as f64 / tiempo_total as f64
+        } else {
+            0.0
+        };
+        let porcentaje = if asignado_total > 0 {
+            desperdicio_total as f64 * 100.0 / asignado_total as f64
+        } else {
+            0.0
+        };
+        resumen.push(format!(
+            "Fragmentación interna media: {:.2} KB ({:.2}% de la memoria asignada a procesos).",
+            desperdicio_medio,
+            porcentaje
+        ));
+        resumen.extend(self.estrategia.resumen(tiempo_total));
+        resumen
+    }
+}
@@ -0,0 +1,44 @@
+/// Planificación de la CPU: decide cuál de los procesos listos en memoria usa el único procesador.
+#[derive(Debug, Clone)]
+pub enum PlanificacionCpu {
+    Fcfs,            // Por orden de llegada a la cola de listos, sin expropiación
+    Sjf,             // El de menor duración restante, sin expropiación
+    Srtf,            // El de menor duración restante, con expropiación al llegar uno más corto
+    RoundRobin(u64), // Por orden de llegada, con un quantum de N unidades de tiempo
+}
+
+impl PlanificacionCpu {
+    /// Elige al próximo proceso de la cola de listos (en orden de llegada a la cola).
+    /// Devuelve su posición dentro de la cola.
+    pub fn elegir(&self, cola_listos: &[usize], tiempo_restante: &[u64]) -> Option<usize> {
+        match self {
+            PlanificacionCpu::Fcfs | PlanificacionCpu::RoundRobin(_) => {
+                if cola_listos.is_empty() {
+                    None
+                } else {
+                    Some(0)
+                }
+            }
+            // min_by_key devuelve el primero entre los empatados: a igual duración, el que llegó antes
+            PlanificacionCpu::Sjf | PlanificacionCpu::Srtf => cola_listos
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, &indice)| tiempo_restante[indice])
+                .map(|(posicion, _)| posicion),
+        }
+    }
+
+    /// Indica si un proceso listo con `restante` unidades de ejecución desaloja al que está
+    /// en la CPU, al que le quedan `restante_en_cpu`
+    pub fn desaloja(&self, restante: u64, restante_en_cpu: u64) -> bool {
+        matches!(self, PlanificacionCpu::Srtf) && restante < restante_en_cpu
+    }
+
+    /// Duración máxima de la ráfaga que se le concede a un proceso que toma la CPU
+    pub fn rafaga(&self, tiempo_restante: u64) -> u64 {
+        match self {
+            PlanificacionCpu::RoundRobin(quantum) => tiempo_restante.min(*quantum),
+            _ => tiempo_restante,
+        }
+    }
+}
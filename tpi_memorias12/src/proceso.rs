@@ -1,11 +1,47 @@
 //proceso.rs
 
+use crate::slab::{TipoObjeto, TIPOS_OBJETO};
+
 #[derive(Clone)]  // Para que podamos clonar procesos si es necesario
 pub struct Proceso {
     pub nombre: String,
     pub arribo: usize,
     pub duracion: usize,
     pub memoria_requerida: u32,
+    pub segmentos: Vec<Segmento>, // Vacío si el proceso no declara segmentos
+    pub rafagas: Vec<Rafaga>,     // Vacío si el proceso es una única ráfaga de CPU
+    pub cambios_memoria: Vec<CambioMemoria>, // Pedidos de más o menos memoria durante la ejecución
+    pub prioridad: u32,           // 0 es la prioridad más alta
+    pub paciencia: Option<u64>,   // Tiempo máximo que espera memoria antes de abandonar
+    pub objetos: Vec<PedidoObjetos>, // Objetos del kernel que pide en modo slab (vacío si no declara ninguno)
+}
+
+/// Segmento de un proceso (código, datos, pila...)
+#[derive(Clone)]
+pub struct Segmento {
+    pub nombre: String,
+    pub tamanio: u32, // KB
+}
+
+/// Cantidad de objetos de un mismo tipo que pide un proceso en modo slab
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PedidoObjetos {
+    pub tipo: TipoObjeto,
+    pub cantidad: u32,
+}
+
+/// Pedido de memoria que el proceso hace mientras ejecuta (como un `brk` o un `realloc`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CambioMemoria {
+    pub tras_ejecutar: u64, // Unidades de CPU que el proceso lleva ejecutadas al hacer el pedido
+    pub cambio: i32,        // KB que crece (positivo) o que devuelve (negativo)
+}
+
+/// Fase de la ejecución de un proceso: usa la CPU o espera a un dispositivo de E/S
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rafaga {
+    Cpu(u64),
+    EntradaSalida { dispositivo: usize, duracion: u64 },
 }
 
 impl Proceso {
@@ -16,9 +52,90 @@ impl Proceso {
             arribo,
             duracion,
             memoria_requerida,
+            segmentos: Vec::new(),
+            rafagas: Vec::new(),
+            cambios_memoria: Vec::new(),
+            prioridad: 0,
+            paciencia: None,
+            objetos: Vec::new(),
+        }
+    }
+
+    /// Crea un proceso formado por varios segmentos; su memoria requerida es la suma de ellos
+    pub fn con_segmentos(nombre: &str, arribo: usize, duracion: usize, segmentos: Vec<Segmento>) -> Proceso {
+        Proceso {
+            nombre: nombre.to_string(),
+            arribo,
+            duracion,
+            memoria_requerida: segmentos.iter().map(|s| s.tamanio).sum(),
+            segmentos,
+            rafagas: Vec::new(),
+            cambios_memoria: Vec::new(),
+            prioridad: 0,
+            paciencia: None,
+            objetos: Vec::new(),
         }
     }
 
+    /// Reemplaza la ejecución del proceso por ráfagas de CPU y de E/S; su duración pasa a ser
+    /// la suma de las ráfagas de CPU
+    pub fn con_rafagas(mut self, rafagas: Vec<Rafaga>) -> Proceso {
+        self.duracion = rafagas
+            .iter()
+            .map(|r| match r {
+                Rafaga::Cpu(duracion) => *duracion as usize,
+                Rafaga::EntradaSalida { .. } => 0,
+            })
+            .sum();
+        self.rafagas = rafagas;
+        self
+    }
+
+    /// Agrega los pedidos de memoria que hará el proceso, ordenados según cuándo los hace
+    pub fn con_cambios_memoria(mut self, mut cambios: Vec<CambioMemoria>) -> Proceso {
+        cambios.sort_by_key(|c| c.tras_ejecutar);
+        self.cambios_memoria = cambios;
+        self
+    }
+
+    /// Asigna la prioridad del proceso (0 es la más alta)
+    pub fn con_prioridad(mut self, prioridad: u32) -> Proceso {
+        self.prioridad = prioridad;
+        self
+    }
+
+    /// Asigna el tiempo máximo que el proceso espera memoria antes de abandonar
+    pub fn con_paciencia(mut self, paciencia: u64) -> Proceso {
+        self.paciencia = Some(paciencia);
+        self
+    }
+
+    /// Declara los objetos del kernel que pide el proceso; su memoria requerida pasa a ser la suma de ellos
+    pub fn con_objetos(mut self, objetos: Vec<PedidoObjetos>) -> Proceso {
+        self.memoria_requerida = objetos.iter().map(|o| o.cantidad * o.tipo.tamanio()).sum();
+        self.objetos = objetos;
+        self
+    }
+
+    /// Copia del proceso con `memoria` KB. Si tiene segmentos, la diferencia la absorbe el de datos
+    /// (o el último, si no tiene uno con ese nombre), que nunca queda por debajo de 1 KB.
+    pub fn redimensionado(&self, memoria: u32) -> Proceso {
+        let mut proceso = self.clone();
+        if let Some(segmento) = proceso
+            .segmentos
+            .iter()
+            .position(|s| s.nombre == "Datos")
+            .or(proceso.segmentos.len().checked_sub(1))
+        {
+            let resto = self.memoria_requerida - self.segmentos[segmento].tamanio;
+            proceso.segmentos[segmento].tamanio = memoria.saturating_sub(resto).max(1);
+            proceso.memoria_requerida = proceso.segmentos.iter().map(|s| s.tamanio).sum();
+        } else {
+            proceso.memoria_requerida = memoria.max(1);
+        }
+        proceso
+    }
+
     /// Obtener el nombre del proceso
     pub fn get_nombre(&self) -> &str {
         &self.nombre
@@ -38,4 +155,109 @@ impl Proceso {
     pub fn get_memoria_requerida(&self) -> u32 {
         self.memoria_requerida
     }
+
+    /// Obtener la prioridad del proceso (0 es la más alta)
+    pub fn get_prioridad(&self) -> u32 {
+        self.prioridad
+    }
+
+    /// Obtener la paciencia del proceso, si tiene
+    pub fn get_paciencia(&self) -> Option<u64> {
+        self.paciencia
+    }
+
+    /// Obtener los segmentos del proceso. Si no declaró ninguno, es un único segmento con toda su memoria.
+    pub fn get_segmentos(&self) -> Vec<Segmento> {
+        if self.segmentos.is_empty() {
+            vec![Segmento {
+                nombre: "Proceso".to_string(),
+                tamanio: self.memoria_requerida,
+            }]
+        } else {
+            self.segmentos.clone()
+        }
+    }
+
+    /// Obtener los objetos que el proceso pide en modo slab, agrupados por tipo. Se respetan los declarados
+    /// mientras entren en su memoria requerida; lo que falte se cubre con los objetos más grandes que entren
+    /// y el último resto con un objeto del tipo más chico.
+    pub fn get_objetos(&self) -> Vec<PedidoObjetos> {
+        let mut objetos: Vec<PedidoObjetos> = Vec::new();
+        let mut agregar = |tipo: TipoObjeto, cantidad: u32| {
+            if cantidad == 0 {
+                return;
+            }
+            match objetos.iter_mut().find(|o| o.tipo == tipo) {
+                Some(pedido) => pedido.cantidad += cantidad,
+                None => objetos.push(PedidoObjetos { tipo, cantidad }),
+            }
+        };
+
+        // Después de los declarados se prueban todos los tipos, del más grande al más chico
+        let completar = TIPOS_OBJETO.map(|tipo| PedidoObjetos { tipo, cantidad: u32::MAX });
+        let mut restante = self.memoria_requerida;
+        for pedido in self.objetos.iter().chain(completar.iter()) {
+            let cantidad = pedido.cantidad.min(restante / pedido.tipo.tamanio());
+            agregar(pedido.tipo, cantidad);
+            restante -= cantidad * pedido.tipo.tamanio();
+        }
+        if restante > 0 {
+            agregar(TIPOS_OBJETO[TIPOS_OBJETO.len() - 1], 1);
+        }
+        objetos
+    }
+
+    /// Descripción de los objetos declarados para tablas y mensajes (ej: "tarea 2, inodo 5")
+    pub fn descripcion_objetos(&self) -> String {
+        if self.objetos.is_empty() {
+            return "-".to_string();
+        }
+        self.objetos
+            .iter()
+            .map(|o| format!("{} {}", o.tipo.nombre(), o.cantidad))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Descripción de los segmentos para tablas y mensajes (ej: "Código 120, Datos 80, Pila 40")
+    pub fn descripcion_segmentos(&self) -> String {
+        self.get_segmentos()
+            .iter()
+            .map(|s| format!("{} {}", s.nombre, s.tamanio))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Obtener las ráfagas del proceso. Si no declaró ninguna, es una única ráfaga de CPU con toda su duración.
+    pub fn get_rafagas(&self) -> Vec<Rafaga> {
+        if self.rafagas.is_empty() {
+            vec![Rafaga::Cpu(self.duracion as u64)]
+        } else {
+            self.rafagas.clone()
+        }
+    }
+
+    /// Descripción de las ráfagas para tablas y mensajes (ej: "CPU 3, E/S 4 (disp. 0), CPU 2")
+    pub fn descripcion_rafagas(&self) -> String {
+        self.get_rafagas()
+            .iter()
+            .map(|r| match r {
+                Rafaga::Cpu(duracion) => format!("CPU {}", duracion),
+                Rafaga::EntradaSalida { dispositivo, duracion } => format!("E/S {} (disp. {})", duracion, dispositivo),
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Descripción de los pedidos de memoria para tablas y mensajes (ej: "+50 KB a los 3, -20 KB a los 5")
+    pub fn descripcion_cambios_memoria(&self) -> String {
+        if self.cambios_memoria.is_empty() {
+            return "-".to_string();
+        }
+        self.cambios_memoria
+            .iter()
+            .map(|c| format!("{:+} KB a los {}", c.cambio, c.tras_ejecutar))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
 }
@@ -0,0 +1,267 @@
+//segmentacion.rs
+
+//Segmentación: cada proceso se divide en segmentos (código, datos, pila...) que se ubican por
+//separado en particiones dinámicas, cada uno con la estrategia de asignación configurada.
+//Un proceso sólo se carga si todos sus segmentos entran; su tabla de segmentos guarda la base
+//y el límite de cada uno.
+
+use crate::compactacion;
+use crate::estrategias::{EstadisticasBusqueda, EstrategiaAsignacion};
+use crate::memoria::{self, ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::{self, EstadoParticion, Particion};
+use crate::proceso::Proceso;
+
+/// Entrada de la tabla de segmentos de un proceso
+struct EntradaSegmento {
+    nombre: String,
+    base: u32,   // Dirección donde comienza el segmento
+    limite: u32, // Tamaño del segmento en KB
+}
+
+/// Tabla de segmentos de un proceso en memoria
+struct TablaSegmentos {
+    id_proceso: String,
+    segmentos: Vec<EntradaSegmento>,
+}
+
+pub struct MemoriaSegmentada {
+    particiones: Vec<Particion>,
+    estrategia: Box<dyn EstrategiaAsignacion>,
+    ultima_asignada: usize, // Dirección donde terminó la última asignación (Next-fit)
+    tamanio_memoria: u32,
+    tablas: Vec<TablaSegmentos>,
+    mensajes: Vec<String>,
+    segmentos_ubicados: u32,
+    rechazos_parciales: u32, // Intentos en los que entraban algunos segmentos pero no todos
+    busquedas: EstadisticasBusqueda, // Cada segmento es una búsqueda
+}
+
+impl MemoriaSegmentada {
+    /// Al comenzar, toda la memoria es un único hueco libre.
+    pub fn nueva(tamanio_memoria: u32, estrategia: Box<dyn EstrategiaAsignacion>) -> MemoriaSegmentada {
+        MemoriaSegmentada {
+            particiones: vec![Particion::nueva(0, tamanio_memoria)],
+            estrategia,
+            ultima_asignada: 0,
+            tamanio_memoria,
+            tablas: Vec::new(),
+            mensajes: Vec::new(),
+            segmentos_ubicados: 0,
+            rechazos_parciales: 0,
+            busquedas: EstadisticasBusqueda::default(),
+        }
+    }
+}
+
+impl ModeloMemoria for MemoriaSegmentada {
+    /// Los segmentos se ubican de a uno sobre una copia de la memoria; si alguno no entra,
+    /// se descarta la copia y el proceso sigue esperando.
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let mut particiones = self.particiones.clone();
+        let mut ultima_asignada = self.ultima_asignada;
+        let mut entradas: Vec<EntradaSegmento> = Vec::new();
+
+        for segmento in proceso.get_segmentos() {
+            // Cada segmento se ubica como un bloque del proceso con el tamaño del segmento
+            let parte = Proceso::new(&proceso.nombre, proceso.arribo, proceso.duracion, segmento.tamanio);
+            match self.estrategia.asignar_proceso(
+                &mut particiones,
+                &parte,
+                &mut ultima_asignada,
+                tiempo_carga,
+                &mut self.busquedas,
+            ) {
+                Some(base) => entradas.push(EntradaSegmento {
+                    nombre: segmento.nombre,
+                    base,
+                    limite: segmento.tamanio,
+                }),
+                None => {
+                    if !entradas.is_empty() {
+                        self.rechazos_parciales += 1;
+                    }
+                    self.mensajes.push(format!(
+                        "el segmento {} ({} KB) del proceso {} no entra en ningún hueco{}; no se carga ninguno de sus segmentos",
+                        segmento.nombre,
+                        segmento.tamanio,
+                        proceso.nombre,
+                        if entradas.is_empty() {
+                            String::new()
+                        } else {
+                            format!(
+                                " (sí entraban: {})",
+                                entradas.iter().map(|e| e.nombre.as_str()).collect::<Vec<&str>>().join(", ")
+                            )
+                        }
+                    ));
+                    return None;
+                }
+            }
+        }
+
+        self.particiones = particiones;
+        self.ultima_asignada = ultima_asignada;
+        self.estrategia.asignacion_confirmada();
+        self.segmentos_ubicados += entradas.len() as u32;
+        self.mensajes.push(format!(
+            "se creó la tabla de segmentos del proceso {}: {}",
+            proceso.nombre,
+            entradas
+                .iter()
+                .map(|e| format!("{} (base {}, límite {} KB)", e.nombre, e.base, e.limite))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+
+        let ubicacion = Ubicacion {
+            direccion: entradas[0].base,
+            tamanio: proceso.get_memoria_requerida(),
+        };
+        self.tablas.push(TablaSegmentos {
+            id_proceso: proceso.nombre.clone(),
+            segmentos: entradas,
+        });
+        Some(ubicacion)
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.estado = estado.clone();
+            }
+        }
+    }
+
+    /// Sólo cambia el segmento cuyo tamaño difiere del de la tabla; se redimensiona como un bloque
+    /// de particiones dinámicas y su entrada se actualiza con la nueva base y el nuevo límite.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let Some(tabla) = self.tablas.iter_mut().find(|t| t.id_proceso == proceso.nombre) else {
+            return Redimension::SinLugar;
+        };
+        let segmentos = proceso.get_segmentos();
+        let Some((entrada, segmento)) = tabla.segmentos.iter_mut().find_map(|entrada| {
+            segmentos
+                .iter()
+                .find(|s| s.nombre == entrada.nombre && s.tamanio != entrada.limite)
+                .map(|segmento| (entrada, segmento))
+        }) else {
+            return Redimension::EnLugar;
+        };
+
+        let parte = Proceso::new(&proceso.nombre, proceso.arribo, proceso.duracion, segmento.tamanio);
+        let resultado = memoria::redimensionar_bloque(
+            &mut self.particiones,
+            entrada.base,
+            &parte,
+            self.estrategia.as_mut(),
+            &mut self.ultima_asignada,
+            &mut self.busquedas,
+        );
+        match &resultado {
+            Redimension::EnLugar => entrada.limite = segmento.tamanio,
+            Redimension::Reubicado(ubicacion) => {
+                entrada.base = ubicacion.direccion;
+                entrada.limite = segmento.tamanio;
+            }
+            Redimension::SinLugar => return resultado,
+        }
+        self.mensajes.push(format!(
+            "el segmento {} del proceso {} ahora tiene base {} y límite {} KB",
+            entrada.nombre, proceso.nombre, entrada.base, entrada.limite
+        ));
+        resultado
+    }
+
+    /// Todos los segmentos del proceso quedan libres y se combinan con los huecos vecinos.
+    fn liberar(&mut self, nombre_proceso: &str) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.liberar();
+                self.estrategia.hueco_liberado(particion.direccion_comienzo);
+            }
+        }
+        particion::combinar_particiones(&mut self.particiones);
+        self.tablas.retain(|t| t.id_proceso != nombre_proceso);
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.tamanio_memoria
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.particiones.iter().filter(|p| !p.esta_libre()).map(|p| p.tamanio).sum()
+    }
+
+    fn huecos(&self) -> Vec<u32> {
+        self.particiones.iter().filter(|p| p.esta_libre()).map(|p| p.tamanio).collect()
+    }
+
+    /// Al compactar, la base de cada segmento se actualiza con su nueva dirección.
+    fn compactar(&mut self) -> u32 {
+        let bases_anteriores: Vec<u32> = self
+            .particiones
+            .iter()
+            .filter(|p| !p.esta_libre())
+            .map(|p| p.direccion_comienzo)
+            .collect();
+        let kb_movidos = compactacion::compactar(&mut self.particiones);
+
+        // La compactación conserva el orden de los segmentos
+        for (anterior, particion) in bases_anteriores.iter().zip(self.particiones.iter()) {
+            for entrada in self.tablas.iter_mut().flat_map(|t| t.segmentos.iter_mut()) {
+                if entrada.base == *anterior {
+                    entrada.base = particion.direccion_comienzo;
+                    break;
+                }
+            }
+        }
+        self.ultima_asignada = (self.memoria_ocupada() % self.tamanio_memoria) as usize;
+        kb_movidos
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        self.estrategia.avanzar_tiempo(duracion);
+    }
+
+    /// Los mensajes de la estrategia van primero: sus decisiones se toman antes de ubicar los segmentos
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        let mut mensajes = self.estrategia.tomar_mensajes();
+        mensajes.append(&mut self.mensajes);
+        mensajes
+    }
+
+    /// Segmentos en memoria, en orden de dirección
+    fn mapa(&self) -> Option<String> {
+        let mut segmentos: Vec<(u32, String)> = self
+            .tablas
+            .iter()
+            .flat_map(|t| {
+                t.segmentos
+                    .iter()
+                    .map(move |e| (e.base, format!("[{}-{}] {} {}", e.base, e.base + e.limite - 1, t.id_proceso, e.nombre)))
+            })
+            .collect();
+        segmentos.sort();
+        if segmentos.is_empty() {
+            return Some("Mapa de segmentos: memoria libre".to_string());
+        }
+        Some(format!(
+            "Mapa de segmentos: {}",
+            segmentos.into_iter().map(|(_, s)| s).collect::<Vec<String>>().join(" | ")
+        ))
+    }
+
+    fn estadisticas_busqueda(&self) -> Option<EstadisticasBusqueda> {
+        Some(self.busquedas)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = vec![format!(
+            "Segmentación: {} segmentos ubicados. Rechazos parciales (entraban algunos segmentos pero no todos): {}.",
+            self.segmentos_ubicados,
+            self.rechazos_parciales
+        )];
+        resumen.extend(self.estrategia.resumen(tiempo_total));
+        resumen
+    }
+}
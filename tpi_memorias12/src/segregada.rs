@@ -0,0 +1,367 @@
+//segregada.rs
+
+//Ajuste segregado (segregated fit): los bloques libres se reparten en listas según su tamaño.
+//- Listas rápidas (quick-fit): una por cada tamaño frecuente configurado. Un bloque de ese tamaño
+//  exacto que se libera va a su lista sin combinarse, para entregarlo de inmediato al próximo pedido
+//  igual. Sólo si un pedido no encuentra lugar se vacían las listas rápidas y se combina todo.
+//- Clases por potencias de 2: la clase k guarda los bloques libres de 2^k a 2^(k+1) - 1 KB.
+//- Lista general: los bloques a partir de un tamaño límite.
+//Un pedido de un tamaño frecuente busca primero en su lista rápida. Si no, busca en su clase el primer
+//bloque que alcance y, si no hay, toma el primero de la clase mayor más chica que tenga bloques (o de
+//la lista general); el sobrante se divide y vuelve a la lista que le corresponde.
+
+use crate::memoria::{ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::{EstadoParticion, Particion};
+use crate::proceso::Proceso;
+
+/// Lista de bloques libres a la que pertenece un tamaño
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lista {
+    Rapida(usize), // Índice del tamaño dentro de `tamanios_rapidos`
+    Clase(u32),    // Bloques de 2^k a 2^(k+1) - 1 KB
+    General,
+}
+
+/// Pedidos que tenían a una lista como propia y cuántos se resolvieron en ella
+#[derive(Debug, Clone, Copy, Default)]
+struct UsoLista {
+    pedidos: u32,
+    aciertos: u32,
+}
+
+pub struct MemoriaSegregada {
+    particiones: Vec<Particion>, // Bloques libres y ocupados, en orden de dirección
+    en_lista_rapida: Vec<bool>,  // Bloques libres retenidos en una lista rápida (no se combinan)
+    tamanios_rapidos: Vec<u32>,
+    limite_general: u32, // Los bloques de este tamaño o más van a la lista general
+    tamanio_memoria: u32,
+    mensajes: Vec<String>,
+    uso_rapidas: Vec<UsoLista>,
+    uso_clases: Vec<UsoLista>, // Por clase k
+    uso_general: UsoLista,
+    divisiones: u32,
+    combinaciones: u32,
+    vaciados: u32, // Veces que se vaciaron las listas rápidas por falta de lugar
+}
+
+impl MemoriaSegregada {
+    /// Al comenzar, toda la memoria es un único bloque libre.
+    pub fn nueva(tamanio_memoria: u32, tamanios_rapidos: &[u32], limite_general: u32) -> MemoriaSegregada {
+        MemoriaSegregada {
+            particiones: vec![Particion::nueva(0, tamanio_memoria)],
+            en_lista_rapida: vec![false],
+            tamanios_rapidos: tamanios_rapidos.to_vec(),
+            limite_general,
+            tamanio_memoria,
+            mensajes: Vec::new(),
+            uso_rapidas: vec![UsoLista::default(); tamanios_rapidos.len()],
+            uso_clases: vec![UsoLista::default(); 32],
+            uso_general: UsoLista::default(),
+            divisiones: 0,
+            combinaciones: 0,
+            vaciados: 0,
+        }
+    }
+
+    /// Lista que le corresponde a un pedido (o a un bloque liberado) de `tamanio` KB
+    fn lista_de(&self, tamanio: u32) -> Lista {
+        match self.tamanios_rapidos.iter().position(|&t| t == tamanio) {
+            Some(indice) => Lista::Rapida(indice),
+            None => self.lista_por_tamanio(tamanio),
+        }
+    }
+
+    /// Clase o lista general que le corresponde a un bloque de `tamanio` KB, sin contar las listas rápidas
+    fn lista_por_tamanio(&self, tamanio: u32) -> Lista {
+        if tamanio >= self.limite_general {
+            Lista::General
+        } else {
+            Lista::Clase(31 - tamanio.max(1).leading_zeros())
+        }
+    }
+
+    fn uso(&mut self, lista: Lista) -> &mut UsoLista {
+        match lista {
+            Lista::Rapida(indice) => &mut self.uso_rapidas[indice],
+            Lista::Clase(clase) => &mut self.uso_clases[clase as usize],
+            Lista::General => &mut self.uso_general,
+        }
+    }
+
+    fn descripcion_lista(&self, lista: Lista) -> String {
+        match lista {
+            Lista::Rapida(indice) => format!("lista rápida de {} KB", self.tamanios_rapidos[indice]),
+            Lista::Clase(clase) => format!(
+                "clase de {} a {} KB",
+                1u32 << clase,
+                ((1u64 << (clase + 1)) - 1).min(self.limite_general as u64 - 1)
+            ),
+            Lista::General => format!("lista general (desde {} KB)", self.limite_general),
+        }
+    }
+
+    /// Primer bloque libre de una lista, en orden de dirección, que alcanza para `tamanio` KB
+    fn buscar_en(&self, lista: Lista, tamanio: u32) -> Option<usize> {
+        (0..self.particiones.len()).find(|&indice| {
+            let bloque = &self.particiones[indice];
+            bloque.esta_libre() && bloque.tamanio >= tamanio && self.lista_de_libre(indice) == lista
+        })
+    }
+
+    /// Lista en la que está un bloque libre: los retenidos están en su lista rápida y
+    /// los demás en la clase o la lista general según su tamaño
+    fn lista_de_libre(&self, indice: usize) -> Lista {
+        let tamanio = self.particiones[indice].tamanio;
+        if self.en_lista_rapida[indice] {
+            self.lista_de(tamanio)
+        } else {
+            self.lista_por_tamanio(tamanio)
+        }
+    }
+
+    /// Busca un bloque para el pedido: primero en su propia lista y luego en las de bloques más grandes
+    fn buscar_bloque(&self, tamanio: u32) -> Option<(usize, Lista)> {
+        let propia = self.lista_de(tamanio);
+        if let Some(indice) = self.buscar_en(propia, tamanio) {
+            return Some((indice, propia));
+        }
+        let por_tamanio = self.lista_por_tamanio(tamanio);
+        let Lista::Clase(clase) = por_tamanio else {
+            return self.buscar_en(Lista::General, tamanio).map(|indice| (indice, Lista::General));
+        };
+        // Si el pedido tiene lista rápida, su clase también puede tener bloques que alcancen
+        let desde = if por_tamanio == propia { clase + 1 } else { clase };
+        (desde..32)
+            .map(Lista::Clase)
+            .chain(std::iter::once(Lista::General))
+            .find_map(|lista| self.buscar_en(lista, tamanio).map(|indice| (indice, lista)))
+    }
+
+    /// Combina el bloque libre `indice` con sus vecinos libres que no estén retenidos en una lista rápida
+    fn combinar_vecinos(&mut self, indice: usize) {
+        if indice + 1 < self.particiones.len()
+            && self.particiones[indice + 1].esta_libre()
+            && !self.en_lista_rapida[indice + 1]
+        {
+            self.combinar_con_siguiente(indice);
+        }
+        if indice > 0 && self.particiones[indice - 1].esta_libre() && !self.en_lista_rapida[indice - 1] {
+            self.combinar_con_siguiente(indice - 1);
+        }
+    }
+
+    fn combinar_con_siguiente(&mut self, indice: usize) {
+        let siguiente = self.particiones.remove(indice + 1);
+        self.en_lista_rapida.remove(indice + 1);
+        self.combinaciones += 1;
+        self.mensajes.push(format!(
+            "se combinó el bloque libre de {} KB de la dirección {} con el de {} KB de la dirección {}",
+            self.particiones[indice].tamanio,
+            self.particiones[indice].direccion_comienzo,
+            siguiente.tamanio,
+            siguiente.direccion_comienzo
+        ));
+        self.particiones[indice].tamanio += siguiente.tamanio;
+    }
+
+    /// Devuelve a las clases todos los bloques de las listas rápidas y los combina con sus vecinos
+    fn vaciar_listas_rapidas(&mut self) -> bool {
+        if !self.en_lista_rapida.iter().any(|&retenido| retenido) {
+            return false;
+        }
+        self.vaciados += 1;
+        self.mensajes.push("se vaciaron las listas rápidas para combinar sus bloques".to_string());
+        self.en_lista_rapida.iter_mut().for_each(|retenido| *retenido = false);
+        let mut indice = 0;
+        while indice + 1 < self.particiones.len() {
+            if self.particiones[indice].esta_libre() && self.particiones[indice + 1].esta_libre() {
+                self.combinar_con_siguiente(indice);
+            } else {
+                indice += 1;
+            }
+        }
+        true
+    }
+
+    /// Libera el bloque `indice`: si su tamaño tiene lista rápida queda retenido en ella sin
+    /// combinarse; si no, se combina con sus vecinos libres.
+    fn liberar_bloque(&mut self, indice: usize) {
+        self.particiones[indice].liberar();
+        if let Lista::Rapida(_) = self.lista_de(self.particiones[indice].tamanio) {
+            self.en_lista_rapida[indice] = true;
+        } else {
+            self.combinar_vecinos(indice);
+        }
+    }
+
+    /// Ocupa el comienzo del bloque libre `indice` con `tamanio` KB; el sobrante queda libre
+    fn dividir(&mut self, indice: usize, tamanio: u32) {
+        let bloque = &self.particiones[indice];
+        if bloque.tamanio > tamanio {
+            let sobrante = Particion::nueva(bloque.direccion_comienzo + tamanio, bloque.tamanio - tamanio);
+            let lista = self.descripcion_lista(self.lista_por_tamanio(sobrante.tamanio));
+            self.divisiones += 1;
+            self.mensajes.push(format!(
+                "se dividió el bloque de {} KB de la dirección {}: {} KB para el pedido y {} KB libres a la {}",
+                bloque.tamanio, bloque.direccion_comienzo, tamanio, sobrante.tamanio, lista
+            ));
+            self.particiones[indice].tamanio = tamanio;
+            self.particiones.insert(indice + 1, sobrante);
+            self.en_lista_rapida.insert(indice + 1, false);
+        }
+        self.en_lista_rapida[indice] = false;
+    }
+}
+
+impl ModeloMemoria for MemoriaSegregada {
+    fn asignar(&mut self, proceso: &Proceso, tiempo_carga: u32) -> Option<Ubicacion> {
+        let tamanio = proceso.get_memoria_requerida().max(1);
+        let propia = self.lista_de(tamanio);
+        self.uso(propia).pedidos += 1;
+
+        let (indice, lista) = match self.buscar_bloque(tamanio) {
+            Some(encontrado) => encontrado,
+            None => {
+                // Los bloques retenidos pueden alcanzar una vez combinados
+                if !self.vaciar_listas_rapidas() {
+                    return None;
+                }
+                self.buscar_bloque(tamanio)?
+            }
+        };
+        if lista == propia {
+            self.uso(propia).aciertos += 1;
+        }
+
+        self.dividir(indice, tamanio);
+        let particion = &mut self.particiones[indice];
+        particion.ocupar(proceso.nombre.clone(), proceso.arribo as u32, tiempo_carga);
+        Some(Ubicacion {
+            direccion: particion.direccion_comienzo,
+            tamanio,
+        })
+    }
+
+    fn cambiar_estado(&mut self, nombre_proceso: &str, estado: EstadoParticion) {
+        for particion in self.particiones.iter_mut() {
+            if particion.id_proceso.as_deref() == Some(nombre_proceso) {
+                particion.estado = estado.clone();
+            }
+        }
+    }
+
+    /// Al achicarse, el sobrante se libera como un bloque más; al crecer se toma el bloque libre
+    /// siguiente si alcanza, y si no se pide un bloque nuevo y se libera el anterior.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let Some(indice) = self
+            .particiones
+            .iter()
+            .position(|p| p.id_proceso.as_deref() == Some(proceso.get_nombre()))
+        else {
+            return Redimension::SinLugar;
+        };
+        let nuevo = proceso.get_memoria_requerida().max(1);
+        let actual = self.particiones[indice].tamanio;
+
+        if nuevo <= actual {
+            if nuevo < actual {
+                let sobrante = Particion::nueva(self.particiones[indice].direccion_comienzo + nuevo, actual - nuevo);
+                self.particiones[indice].tamanio = nuevo;
+                self.particiones.insert(indice + 1, sobrante);
+                self.en_lista_rapida.insert(indice + 1, false);
+                self.liberar_bloque(indice + 1);
+            }
+            return Redimension::EnLugar;
+        }
+
+        // Los bloques retenidos en una lista rápida quedan para los pedidos de su tamaño
+        let falta = nuevo - actual;
+        if indice + 1 < self.particiones.len()
+            && self.particiones[indice + 1].esta_libre()
+            && !self.en_lista_rapida[indice + 1]
+            && self.particiones[indice + 1].tamanio >= falta
+        {
+            self.dividir(indice + 1, falta);
+            self.particiones.remove(indice + 1);
+            self.en_lista_rapida.remove(indice + 1);
+            self.particiones[indice].tamanio = nuevo;
+            return Redimension::EnLugar;
+        }
+
+        // Mientras se busca el bloque nuevo, el anterior sigue ocupado
+        let direccion = self.particiones[indice].direccion_comienzo;
+        let estado = self.particiones[indice].estado.clone();
+        let Some(ubicacion) = self.asignar(proceso, 0) else {
+            return Redimension::SinLugar;
+        };
+        if let Some(anterior) = self.particiones.iter().position(|p| p.direccion_comienzo == direccion) {
+            self.liberar_bloque(anterior);
+        }
+        self.cambiar_estado(proceso.get_nombre(), estado);
+        Redimension::Reubicado(ubicacion)
+    }
+
+    fn liberar(&mut self, nombre_proceso: &str) {
+        if let Some(indice) = self
+            .particiones
+            .iter()
+            .position(|p| p.id_proceso.as_deref() == Some(nombre_proceso))
+        {
+            self.liberar_bloque(indice);
+        }
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.tamanio_memoria
+    }
+
+    fn memoria_ocupada(&self) -> u32 {
+        self.particiones.iter().filter(|p| !p.esta_libre()).map(|p| p.tamanio).sum()
+    }
+
+    /// Cada bloque libre es un hueco: los retenidos en listas rápidas no se combinan con sus vecinos
+    fn huecos(&self) -> Vec<u32> {
+        self.particiones.iter().filter(|p| p.esta_libre()).map(|p| p.tamanio).collect()
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.mensajes)
+    }
+
+    fn resumen(&self, _tiempo_total: u64) -> Vec<String> {
+        let mut resumen = vec![format!(
+            "Ajuste segregado: {} listas rápidas, clases por potencias de 2 hasta {} KB y lista general desde {} KB.",
+            self.tamanios_rapidos.len(),
+            self.limite_general - 1,
+            self.limite_general
+        )];
+        resumen.push(format!(
+            "Divisiones de bloques: {}. Combinaciones de bloques: {}. Vaciados de las listas rápidas: {}.",
+            self.divisiones, self.combinaciones, self.vaciados
+        ));
+
+        // Tasa de acierto: pedidos que se resolvieron con un bloque de su propia lista
+        let listas = (0..self.tamanios_rapidos.len())
+            .map(Lista::Rapida)
+            .chain((0..32).map(Lista::Clase))
+            .chain(std::iter::once(Lista::General));
+        for lista in listas {
+            let uso = match lista {
+                Lista::Rapida(indice) => self.uso_rapidas[indice],
+                Lista::Clase(clase) => self.uso_clases[clase as usize],
+                Lista::General => self.uso_general,
+            };
+            if uso.pedidos == 0 {
+                continue;
+            }
+            resumen.push(format!(
+                "Ajuste segregado, {}: {} pedidos, {} aciertos en la propia lista ({:.2}%).",
+                self.descripcion_lista(lista),
+                uso.pedidos,
+                uso.aciertos,
+                uso.aciertos as f64 * 100.0 / uso.pedidos as f64
+            ));
+        }
+        resumen
+    }
+}
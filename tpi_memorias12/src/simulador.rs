@@ -1,6 +1,221 @@
-use crate::particion::{Particion, EstadoParticion};
-use crate::proceso::Proceso;
+use crate::particion::EstadoParticion;
+use crate::proceso::{Proceso, Rafaga};
 use crate::config::Config;
+use crate::compactacion::{self, Compactacion};
+use crate::memoria::{self, ModeloMemoria, Redimension};
+use crate::swapping::{self, PoliticaSwapping, Residente};
+use crate::planificador::PlanificacionCpu;
+use crate::insatisfacibles::PoliticaInsatisfacibles;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Eventos que hacen avanzar el reloj de la simulación.
+/// Cada evento guarda el índice del proceso dentro de la tanda.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Evento {
+    FinCarga(usize),
+    FinProceso(usize),
+    FinQuantum(usize),
+    FinEntradaSalida(usize),
+    FinLiberacion(usize),
+    CambioMemoria(usize),
+    FinSwapOut(usize),
+    FinCompactacion,
+    CompactacionPeriodica,
+    LlegadaProceso(usize),
+    Abandono(usize), // Se agotó la paciencia de un proceso que espera memoria
+    Rechazo(usize),  // El proceso no puede avanzar (o abandonó la espera) y se descarta
+}
+
+impl Evento {
+    /// Orden de atención de los eventos que ocurren en el mismo instante:
+    /// primero las cargas, luego las finalizaciones, los fines de quantum y de E/S, las liberaciones,
+    /// los pedidos de memoria, las salidas a swap, las compactaciones, los arribos y por último los
+    /// abandonos y los rechazos.
+    fn orden(&self) -> u8 {
+        match self {
+            Evento::FinCarga(_) => 0,
+            Evento::FinProceso(_) => 1,
+            Evento::FinQuantum(_) => 2,
+            Evento::FinEntradaSalida(_) => 3,
+            Evento::FinLiberacion(_) => 4,
+            Evento::CambioMemoria(_) => 5,
+            Evento::FinSwapOut(_) => 6,
+            Evento::FinCompactacion => 7,
+            Evento::CompactacionPeriodica => 8,
+            Evento::LlegadaProceso(_) => 9,
+            Evento::Abandono(_) => 10,
+            Evento::Rechazo(_) => 11,
+        }
+    }
+
+    /// Indica si el evento corresponde a un proceso en memoria y debe postergarse
+    /// mientras la memoria se compacta.
+    fn es_de_proceso_residente(&self) -> bool {
+        matches!(
+            self,
+            Evento::FinCarga(_)
+                | Evento::FinProceso(_)
+                | Evento::FinQuantum(_)
+                | Evento::FinEntradaSalida(_)
+                | Evento::FinLiberacion(_)
+                | Evento::CambioMemoria(_)
+                | Evento::FinSwapOut(_)
+        )
+    }
+}
+
+/// Situación de cada proceso de la tanda durante la simulación
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EstadoProceso {
+    Esperando,     // Todavía no arribó o espera memoria
+    Cargando,      // Tiene memoria asignada y se está cargando (o volviendo del área de swap)
+    Listo,         // Está en memoria esperando la CPU
+    Ejecutando,    // Tiene la CPU
+    Bloqueado,     // Está en memoria esperando (o haciendo) una E/S
+    Suspendido,    // Pidió crecer y no hubo lugar: conserva su memoria y espera que se libere espacio
+    Liberando,
+    SaliendoASwap, // Su memoria se está copiando al área de swap
+    EnSwap,        // Está en el área de swap, esperando volver a memoria
+    Terminado,
+    Rechazado,     // Nunca iba a poder avanzar y se descartó
+}
+
+/// Cómo terminó cada proceso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Desenlace {
+    Terminado,
+    Rechazado,
+    Abandono(u64), // Instante en que abandonó la cola de espera
+    #[default]
+    SinTerminar, // La simulación se abortó antes de que terminara
+}
+
+/// Instantes por los que pasó un proceso durante la simulación y lugar donde se ubicó.
+/// Si pasó por el área de swap, se guardan su primera asignación y su primera carga.
+#[derive(Debug, Clone, Default)]
+pub struct CicloDeVida {
+    pub arribo: u64,
+    pub primer_intento: Option<u64>, // Primera vez que se intentó asignarle memoria
+    pub asignacion: Option<u64>,
+    pub fin_carga: Option<u64>,
+    pub fin_ejecucion: Option<u64>, // Terminó su última ráfaga y comenzó la liberación
+    pub fin_liberacion: Option<u64>,
+    pub direccion: Option<u32>,      // Comienzo del bloque que recibió
+    pub tamanio_bloque: Option<u32>, // KB reservados en ese bloque
+    pub desenlace: Desenlace,
+}
+
+impl CicloDeVida {
+    /// Tiempo de retorno: desde el arribo hasta que su memoria queda libre
+    pub fn retorno(&self) -> Option<u64> {
+        self.fin_liberacion.map(|fin| fin - self.arribo)
+    }
+}
+
+/// Cómo terminó la simulación, para dejarlo indicado en el archivo
+#[derive(Debug, Clone)]
+pub enum EstadoSimulacion {
+    Completa,
+    Incompleta(String), // Motivo por el que no todos los procesos terminaron
+}
+
+/// Estructura para manejar los eventos en el tiempo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EventoSimulado {
+    tiempo: u64,
+    secuencia: u64, // Desempata eventos del mismo tipo en el mismo instante (orden de llegada)
+    evento: Evento,
+}
+
+impl Ord for EventoSimulado {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Queremos que el evento con el menor tiempo tenga mayor prioridad
+        // Por eso comparamos al revés
+        other
+            .tiempo
+            .cmp(&self.tiempo)
+            .then_with(|| other.evento.orden().cmp(&self.evento.orden()))
+            .then_with(|| other.secuencia.cmp(&self.secuencia))
+    }
+}
+
+impl PartialOrd for EventoSimulado {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cola de eventos pendientes, ordenada por tiempo.
+struct ColaEventos {
+    eventos: BinaryHeap<EventoSimulado>,
+    secuencia: u64,
+}
+
+impl ColaEventos {
+    fn nueva() -> ColaEventos {
+        ColaEventos {
+            eventos: BinaryHeap::new(),
+            secuencia: 0,
+        }
+    }
+
+    /// Programa un evento para el instante indicado
+    fn programar(&mut self, tiempo: u64, evento: Evento) {
+        self.eventos.push(EventoSimulado {
+            tiempo,
+            secuencia: self.secuencia,
+            evento,
+        });
+        self.secuencia += 1;
+    }
+
+    /// Instante del próximo evento, si queda alguno
+    fn proximo_tiempo(&self) -> Option<u64> {
+        self.eventos.peek().map(|e| e.tiempo)
+    }
+
+    /// Indica si quedan eventos distintos de la compactación periódica,
+    /// que por sí sola no debe mantener viva la simulación
+    fn hay_eventos_pendientes(&self) -> bool {
+        self.eventos.iter().any(|e| e.evento != Evento::CompactacionPeriodica)
+    }
+
+    /// Posterga los eventos de los procesos residentes, que quedan detenidos durante la compactación
+    fn postergar_residentes(&mut self, demora: u64) {
+        let eventos = std::mem::take(&mut self.eventos);
+        self.eventos = eventos
+            .into_iter()
+            .map(|mut e| {
+                if e.evento.es_de_proceso_residente() {
+                    e.tiempo += demora;
+                }
+                e
+            })
+            .collect();
+    }
+
+    /// Instante para el que está programado un evento pendiente
+    fn tiempo_de(&self, evento: &Evento) -> Option<u64> {
+        self.eventos.iter().find(|e| e.evento == *evento).map(|e| e.tiempo)
+    }
+
+    /// Quita un evento pendiente y devuelve el instante para el que estaba programado
+    fn cancelar(&mut self, evento: &Evento) -> Option<u64> {
+        let tiempo = self.tiempo_de(evento)?;
+        self.eventos.retain(|e| e.evento != *evento);
+        Some(tiempo)
+    }
+
+    /// Extrae el próximo evento si ocurre en el instante indicado
+    fn extraer_en(&mut self, tiempo: u64) -> Option<Evento> {
+        if self.proximo_tiempo() == Some(tiempo) {
+            self.eventos.pop().map(|e| e.evento)
+        } else {
+            None
+        }
+    }
+}
 
 pub struct Simulador;
 
@@ -10,130 +225,1175 @@ impl Simulador {
         configuracion: &Config,
         eventos: &mut Vec<String>,
         resultados: &mut Vec<String>,
-    ) {
+        ciclos_de_vida: &mut Vec<CicloDeVida>,
+    ) -> EstadoSimulacion {
         let mut tiempo_global: u64 = 0;
-        let mut indice_proceso_actual: usize = 0;
-        let mut vector_memoria = crear_vector_memoria(configuracion.tamanio_memoria);
-        let mut ultima_asignada: usize = 0;
-        let mut procesos_liberados: Vec<String> = Vec::new();
-        let mut tiempos_de_retorno: Vec<u64> = vec![0; procesos.len()];
-        let mut particiones_libres_totales: u64 = 0;
+        let mut memoria = memoria::crear_memoria(configuracion);
+        ciclos_de_vida.clear();
+        ciclos_de_vida.extend(procesos.iter().map(|p| CicloDeVida {
+            arribo: p.get_arribo() as u64,
+            ..CicloDeVida::default()
+        }));
+        let mut metricas_memoria = MetricasMemoria::default(); // Ocupación y huecos ponderados por tiempo
+        let mut fallos_asignacion: u32 = 0;
+        let mut fallos_con_memoria_suficiente: u32 = 0; // Fallos con memoria libre total suficiente
+        let mut cola_espera_maxima: usize = 0;
+        let mut cola_espera: Vec<usize> = Vec::new(); // Procesos que arribaron y esperan memoria, en orden de llegada
+        let mut veces_salteado: Vec<u32> = vec![0; procesos.len()];
+        let mut esperando_desde: Vec<u64> = vec![0; procesos.len()]; // Desde cuándo espera memoria cada proceso
+        let mut espera_memoria: Vec<u64> = vec![0; procesos.len()];
+        let mut adelantamientos_totales: u64 = 0;
+        let mut compactando_hasta: u64 = 0; // Mientras se compacta no se admiten procesos
+        let mut estadisticas_compactacion = EstadisticasCompactacion {
+            cantidad: 0,
+            tiempo_total: 0,
+            kb_movidos: 0,
+        };
+        let mut estados: Vec<EstadoProceso> = vec![EstadoProceso::Esperando; procesos.len()];
+        // Ráfagas de cada proceso; el tiempo restante es el de su ráfaga de CPU actual
+        let rafagas: Vec<Vec<Rafaga>> = procesos.iter().map(|p| p.get_rafagas()).collect();
+        let mut rafaga_actual: Vec<usize> = vec![0; procesos.len()];
+        let mut tiempo_restante: Vec<u64> = rafagas
+            .iter()
+            .map(|r| match r.first() {
+                Some(Rafaga::Cpu(duracion)) => *duracion,
+                _ => 0,
+            })
+            .collect();
+        let mut cargado_en: Vec<u64> = vec![0; procesos.len()]; // Instante en que cada proceso entró a memoria
+        let mut ejecutado_desde_carga: Vec<u64> = vec![0; procesos.len()]; // CPU que usó cada proceso desde que entró a memoria
+        let mut desalojo_pedido: Vec<bool> = vec![false; procesos.len()]; // Ya se llevaron procesos al swap en esta espera
+        let mut swap_ocupado: u32 = 0; // KB de los procesos que están (o van) en el área de swap
+        let mut salidas_a_swap_en_curso: u32 = 0;
+        let mut estadisticas_swap: Vec<EstadisticasSwap> = vec![EstadisticasSwap::default(); procesos.len()];
+        let planificacion = &configuracion.planificacion;
+        let mut cola_listos: Vec<usize> = Vec::new(); // Procesos en memoria que esperan la CPU, en orden de llegada a la cola
+        let mut cpu = EstadoCpu::default();
+        let mut listo_desde: Vec<u64> = vec![0; procesos.len()];
+        let mut espera_cpu: Vec<u64> = vec![0; procesos.len()];
+        let mut primera_ejecucion: Vec<Option<u64>> = vec![None; procesos.len()];
+        let mut dispositivos: Vec<Dispositivo> = vec![Dispositivo::default(); configuracion.dispositivos_es as usize];
+        let mut bloqueado_desde: Vec<u64> = vec![0; procesos.len()];
+        let mut tiempo_bloqueado: Vec<u64> = vec![0; procesos.len()];
+        let mut memoria_bloqueada_acumulada: u64 = 0; // KB de procesos bloqueados en E/S por unidad de tiempo
+        let mut fallos_con_bloqueados: u32 = 0; // Asignaciones fallidas mientras había procesos bloqueados en memoria
+        let mut memoria_actual: Vec<u32> = procesos.iter().map(|p| p.get_memoria_requerida()).collect();
+        let mut siguiente_cambio: Vec<usize> = vec![0; procesos.len()]; // Próximo pedido de memoria de cada proceso
+        let mut crecimiento_pendiente: Vec<Option<u32>> = vec![None; procesos.len()]; // Tamaño que esperan los suspendidos
+        let mut estadisticas_redimension = EstadisticasRedimension::default();
+        let mut motivo_rechazo: Vec<Option<String>> = vec![None; procesos.len()];
+        let mut abortada: Option<String> = None; // Diagnóstico, si la simulación se abortó
+        let mut abandono_en: Vec<Option<u64>> = vec![None; procesos.len()]; // Instante en que abandonó la espera
 
-        // Continuar hasta que todos los procesos hayan sido asignados y liberados
-        while !fin_de_la_tanda(indice_proceso_actual, procesos.len(), &vector_memoria) {
-            let mut hubo_cambio = false;
+        // Inicializamos la cola de eventos con los arribos de la tanda
+        let mut cola_eventos = ColaEventos::nueva();
+        for (indice, proceso) in procesos.iter().enumerate() {
+            cola_eventos.programar(proceso.get_arribo() as u64, Evento::LlegadaProceso(indice));
+        }
+        if let Compactacion::Periodica(intervalo) = configuracion.compactacion {
+            cola_eventos.programar(intervalo, Evento::CompactacionPeriodica);
+        }
+
+        // Antes de empezar se descartan los procesos que no entrarían ni con la memoria vacía
+        for (indice, proceso) in procesos.iter().enumerate() {
+            if estados[indice] == EstadoProceso::Rechazado {
+                continue;
+            }
+            let Err(no_cabe) = memoria.cabe_con_memoria_vacia(proceso) else {
+                continue;
+            };
+            let motivo = format!("requiere {} KB y {}", proceso.get_memoria_requerida(), no_cabe);
+            match configuracion.insatisfacibles {
+                PoliticaInsatisfacibles::Rechazar | PoliticaInsatisfacibles::Truncar => {
+                    cola_eventos.cancelar(&Evento::LlegadaProceso(indice));
+                    estados[indice] = EstadoProceso::Rechazado;
+                    eventos.push(format!("Antes de comenzar, se rechaza el proceso {}: {}.", proceso.nombre, motivo));
+                    motivo_rechazo[indice] = Some(motivo);
+                    if let PoliticaInsatisfacibles::Truncar = configuracion.insatisfacibles {
+                        truncar_tanda(&mut cola_eventos, procesos, indice + 1, &mut estados, &mut motivo_rechazo, eventos);
+                    }
+                }
+                PoliticaInsatisfacibles::Abortar => {
+                    let diagnostico = format!("el proceso {} {}", proceso.nombre, motivo);
+                    eventos.push(format!("Antes de comenzar, se aborta la simulación: {}.", diagnostico));
+                    abortada = Some(diagnostico);
+                    break;
+                }
+            }
+        }
+
+        // El reloj salta directamente al instante del próximo evento
+        while let Some(tiempo_evento) = cola_eventos
+            .proximo_tiempo()
+            .filter(|_| cola_eventos.hay_eventos_pendientes() && abortada.is_none())
+        {
+            // Acumular el estado de la memoria del intervalo transcurrido sin cambios en memoria
+            metricas_memoria.acumular(memoria.as_ref(), tiempo_evento - tiempo_global);
+            memoria.avanzar_tiempo(tiempo_evento - tiempo_global);
+            // Mientras se compacta, el proceso que tiene la CPU está detenido
+            if let Some(en_cpu) = cpu.en_cpu.filter(|_| tiempo_global >= compactando_hasta) {
+                cpu.tiempo_ocupada += tiempo_evento - tiempo_global;
+                ejecutado_desde_carga[en_cpu] += tiempo_evento - tiempo_global;
+            }
+            // Lo mismo ocurre con las E/S de los procesos en memoria
+            if tiempo_global >= compactando_hasta {
+                for dispositivo in dispositivos.iter_mut().filter(|d| d.atendiendo.is_some()) {
+                    dispositivo.tiempo_ocupado += tiempo_evento - tiempo_global;
+                }
+            }
+            memoria_bloqueada_acumulada += memoria_bloqueada(&memoria_actual, &estados) as u64 * (tiempo_evento - tiempo_global);
+            tiempo_global = tiempo_evento;
 
             // ===========================
-            // Parte 1: Liberación de memoria
+            // Parte 1: Carga, ejecución, finalización y liberación de memoria
             // ===========================
-            let memoria_liberada = liberar_memoria(
-                &mut vector_memoria,
-                configuracion,
-                &mut procesos_liberados,
-            );
-            if memoria_liberada {
-                if !procesos_liberados.is_empty() {
-                    let memoria_ocupada = calcular_memoria_ocupada(&vector_memoria);
-                    eventos.push(format!(
-                        "En el tiempo global {}, se liberó memoria de los procesos finalizados: {}. (Memoria Ocupada: {} KB de {} total)",
+            let mut procesos_cargados: Vec<String> = Vec::new();
+            let mut procesos_finalizados: Vec<String> = Vec::new();
+            let mut procesos_desbloqueados: Vec<String> = Vec::new();
+            let mut procesos_liberados: Vec<String> = Vec::new();
+            let mut procesos_a_swap: Vec<String> = Vec::new();
+            let mut compactacion_periodica = false;
+
+            while let Some(evento) = cola_eventos.extraer_en(tiempo_global) {
+                let mut rafaga_terminada: Option<usize> = None;
+                match evento {
+                    Evento::FinCarga(indice) => {
+                        let proceso = &procesos[indice];
+                        ciclos_de_vida[indice].fin_carga.get_or_insert(tiempo_global);
+                        memoria.cambiar_estado(proceso.get_nombre(), EstadoParticion::Ocupada);
+                        estados[indice] = EstadoProceso::Listo;
+                        cola_listos.push(indice);
+                        listo_desde[indice] = tiempo_global;
+                        procesos_cargados.push(proceso.nombre.clone());
+                    }
+                    Evento::FinProceso(indice) => {
+                        cpu.en_cpu = None;
+                        tiempo_restante[indice] = 0;
+                        rafaga_terminada = Some(indice);
+                    }
+                    Evento::FinQuantum(indice) => {
+                        tiempo_restante[indice] -= cpu.rafaga;
+                        if cola_listos.is_empty() {
+                            // Nadie espera la CPU: el proceso sigue con un nuevo quantum
+                            programar_rafaga(&mut cola_eventos, &mut cpu, planificacion, indice, tiempo_global, &tiempo_restante);
+                            programar_cambio_memoria(
+                                &mut cola_eventos,
+                                &procesos[indice],
+                                indice,
+                                siguiente_cambio[indice],
+                                cpu_ejecutada(&rafagas[indice], rafaga_actual[indice], tiempo_restante[indice]),
+                                tiempo_global,
+                            );
+                        } else {
+                            cpu.en_cpu = None;
+                            estados[indice] = EstadoProceso::Listo;
+                            cola_listos.push(indice);
+                            listo_desde[indice] = tiempo_global;
+                            eventos.push(format!(
+                                "En el tiempo global {}, el proceso {} agotó su quantum y vuelve a la cola de listos. Le quedan {} unidades de ejecución.",
+                                tiempo_global,
+                                procesos[indice].nombre,
+                                tiempo_restante[indice]
+                            ));
+                        }
+                    }
+                    Evento::FinEntradaSalida(indice) => {
+                        // El dispositivo pasa a atender al siguiente de su cola
+                        if let Some(dispositivo) = dispositivos.iter_mut().find(|d| d.atendiendo == Some(indice)) {
+                            dispositivo.atendiendo = None;
+                            if !dispositivo.cola.is_empty() {
+                                let (siguiente, duracion) = dispositivo.cola.remove(0);
+                                dispositivo.atender(siguiente, duracion, &mut cola_eventos, tiempo_global);
+                            }
+                        }
+                        tiempo_bloqueado[indice] += tiempo_global - bloqueado_desde[indice];
+                        rafaga_terminada = Some(indice);
+                    }
+                    Evento::FinLiberacion(indice) => {
+                        let proceso = &procesos[indice];
+                        memoria.liberar(proceso.get_nombre());
+                        estados[indice] = EstadoProceso::Terminado;
+                        procesos_liberados.push(proceso.nombre.clone());
+                        // El tiempo de retorno abarca desde el arribo hasta que la partición queda libre
+                        ciclos_de_vida[indice].fin_liberacion = Some(tiempo_global);
+                    }
+                    Evento::CambioMemoria(indice) => {
+                        if cpu.en_cpu != Some(indice) {
+                            continue;
+                        }
+                        let proceso = &procesos[indice];
+                        let restante = restante_en_cpu(&cola_eventos, &cpu, tiempo_global, &tiempo_restante);
+                        let cambio = proceso.cambios_memoria[siguiente_cambio[indice]].cambio;
+                        siguiente_cambio[indice] += 1;
+                        let anterior = memoria_actual[indice];
+                        let redimensionado = proceso.redimensionado((anterior as i64 + cambio as i64).max(1) as u32);
+
+                        // Si pide más de lo que el modelo puede darle, nunca va a poder seguir
+                        if let Err(no_cabe) = memoria.cabe_con_memoria_vacia(&redimensionado) {
+                            let motivo = format!(
+                                "pidió crecer de {} a {} KB y {}",
+                                anterior,
+                                redimensionado.get_memoria_requerida(),
+                                no_cabe
+                            );
+                            if let PoliticaInsatisfacibles::Abortar = configuracion.insatisfacibles {
+                                let diagnostico = format!("el proceso {} {}", proceso.nombre, motivo);
+                                eventos.push(format!(
+                                    "En el tiempo global {}, se aborta la simulación: {}.",
+                                    tiempo_global,
+                                    diagnostico
+                                ));
+                                abortada = Some(diagnostico);
+                                break;
+                            }
+                            interrumpir(&mut cola_eventos, &mut cpu, tiempo_global, &mut tiempo_restante);
+                            memoria.liberar(proceso.get_nombre());
+                            estados[indice] = EstadoProceso::Rechazado;
+                            eventos.push(format!(
+                                "En el tiempo global {}, se rechaza el proceso {}: {}. Se libera su memoria.",
+                                tiempo_global,
+                                proceso.nombre,
+                                motivo
+                            ));
+                            motivo_rechazo[indice] = Some(motivo);
+                            if let PoliticaInsatisfacibles::Truncar = configuracion.insatisfacibles {
+                                truncar_tanda(&mut cola_eventos, procesos, 0, &mut estados, &mut motivo_rechazo, eventos);
+                            }
+                            registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                            registrar_mapa_memoria(memoria.as_ref(), eventos);
+                            continue;
+                        }
+
+                        let resultado = memoria.redimensionar(&redimensionado);
+                        registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+
+                        if let Redimension::SinLugar = resultado {
+                            // Sin lugar para crecer: deja la CPU y conserva la memoria que ya tenía
+                            interrumpir(&mut cola_eventos, &mut cpu, tiempo_global, &mut tiempo_restante);
+                            estados[indice] = EstadoProceso::Suspendido;
+                            crecimiento_pendiente[indice] = Some(redimensionado.get_memoria_requerida());
+                            estadisticas_redimension.suspensiones += 1;
+                            eventos.push(format!(
+                                "En el tiempo global {}, el proceso {} pidió crecer de {} a {} KB y no hay lugar: queda suspendido en memoria hasta que se libere espacio.",
+                                tiempo_global,
+                                proceso.nombre,
+                                anterior,
+                                redimensionado.get_memoria_requerida()
+                            ));
+                        } else {
+                            memoria_actual[indice] = redimensionado.get_memoria_requerida();
+                            registrar_redimension(
+                                &resultado,
+                                proceso.get_nombre(),
+                                anterior,
+                                memoria_actual[indice],
+                                tiempo_global,
+                                eventos,
+                                &mut estadisticas_redimension,
+                            );
+                            // Sigue en la CPU: se programa su próximo pedido si cae dentro de la ráfaga
+                            programar_cambio_memoria(
+                                &mut cola_eventos,
+                                proceso,
+                                indice,
+                                siguiente_cambio[indice],
+                                cpu_ejecutada(&rafagas[indice], rafaga_actual[indice], restante),
+                                tiempo_global,
+                            );
+                        }
+                        registrar_mapa_memoria(memoria.as_ref(), eventos);
+                    }
+                    Evento::FinSwapOut(indice) => {
+                        // El proceso queda en el área de swap y vuelve a la cola para regresar a memoria
+                        let proceso = &procesos[indice];
+                        memoria.liberar(proceso.get_nombre());
+                        estados[indice] = EstadoProceso::EnSwap;
+                        salidas_a_swap_en_curso -= 1;
+                        procesos_a_swap.push(proceso.nombre.clone());
+                        cola_espera.push(indice);
+                        esperando_desde[indice] = tiempo_global;
+                        desalojo_pedido[indice] = false;
+                        if let Some(paciencia) = proceso.get_paciencia() {
+                            cola_eventos.programar(tiempo_global + paciencia, Evento::Abandono(indice));
+                        }
+                    }
+                    Evento::FinCompactacion => {
+                        eventos.push(format!(
+                            "En el tiempo global {}, terminó la compactación de la memoria. Se reanudan las asignaciones.",
+                            tiempo_global
+                        ));
+                    }
+                    Evento::CompactacionPeriodica => {
+                        compactacion_periodica = true;
+                        if let Compactacion::Periodica(intervalo) = configuracion.compactacion {
+                            cola_eventos.programar(tiempo_global + intervalo, Evento::CompactacionPeriodica);
+                        }
+                    }
+                    Evento::LlegadaProceso(indice) => {
+                        cola_espera.push(indice);
+                        esperando_desde[indice] = tiempo_global;
+                        if let Some(paciencia) = procesos[indice].get_paciencia() {
+                            cola_eventos.programar(tiempo_global + paciencia, Evento::Abandono(indice));
+                        }
+                    }
+                    Evento::Abandono(indice) => {
+                        // Si hubiera conseguido memoria, el abandono se habría cancelado
+                        motivo_rechazo[indice] = Some(format!(
+                            "abandonó la cola de espera tras {} unidades de tiempo sin conseguir memoria",
+                            tiempo_global - esperando_desde[indice]
+                        ));
+                        abandono_en[indice] = Some(tiempo_global);
+                        cola_eventos.programar(tiempo_global, Evento::Rechazo(indice));
+                    }
+                    Evento::Rechazo(indice) => {
+                        // Un suspendido devuelve la memoria que retenía; uno en swap, su lugar en el área de swap
+                        let proceso = &procesos[indice];
+                        match estados[indice] {
+                            EstadoProceso::Suspendido => {
+                                memoria.liberar(proceso.get_nombre());
+                                crecimiento_pendiente[indice] = None;
+                            }
+                            EstadoProceso::EnSwap => swap_ocupado -= memoria_actual[indice],
+                            _ => {}
+                        }
+                        let liberaba_memoria = estados[indice] == EstadoProceso::Suspendido;
+                        cola_espera.retain(|&otro| otro != indice);
+                        estados[indice] = EstadoProceso::Rechazado;
+                        eventos.push(format!(
+                            "En el tiempo global {}, se rechaza el proceso {}: {}.{}",
+                            tiempo_global,
+                            proceso.nombre,
+                            motivo_rechazo[indice].as_deref().unwrap_or("no puede avanzar"),
+                            if liberaba_memoria { " Se libera su memoria." } else { "" }
+                        ));
+                        if liberaba_memoria {
+                            registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                            registrar_mapa_memoria(memoria.as_ref(), eventos);
+                        }
+                    }
+                }
+
+                // El proceso que terminó una ráfaga pasa a la siguiente: CPU, E/S o fin del proceso
+                if let Some(indice) = rafaga_terminada {
+                    let proceso = &procesos[indice];
+                    rafaga_actual[indice] += 1;
+                    match rafagas[indice].get(rafaga_actual[indice]) {
+                        Some(Rafaga::Cpu(duracion)) => {
+                            tiempo_restante[indice] = *duracion;
+                            estados[indice] = EstadoProceso::Listo;
+                            cola_listos.push(indice);
+                            listo_desde[indice] = tiempo_global;
+                            procesos_desbloqueados.push(proceso.nombre.clone());
+                        }
+                        Some(Rafaga::EntradaSalida { dispositivo, duracion }) => {
+                            // Con menos dispositivos que los que pide la tanda, se reparten en ronda
+                            let numero = dispositivo % dispositivos.len();
+                            estados[indice] = EstadoProceso::Bloqueado;
+                            bloqueado_desde[indice] = tiempo_global;
+                            let en_cola = dispositivos[numero].cola.len() + dispositivos[numero].atendiendo.iter().count();
+                            dispositivos[numero].solicitar(indice, *duracion, &mut cola_eventos, tiempo_global);
+                            eventos.push(format!(
+                                "En el tiempo global {}, el proceso {} se bloquea para hacer E/S en el dispositivo {} ({} unidades de tiempo){}. Sigue ocupando {} KB de memoria.",
+                                tiempo_global,
+                                proceso.nombre,
+                                numero,
+                                duracion,
+                                if en_cola > 0 {
+                                    format!("; espera detrás de {} procesos", en_cola)
+                                } else {
+                                    String::new()
+                                },
+                                memoria_actual[indice]
+                            ));
+                        }
+                        None => {
+                            memoria.cambiar_estado(proceso.get_nombre(), EstadoParticion::Liberando);
+                            estados[indice] = EstadoProceso::Liberando;
+                            ciclos_de_vida[indice].fin_ejecucion = Some(tiempo_global);
+                            procesos_finalizados.push(proceso.nombre.clone());
+                            cola_eventos.programar(tiempo_global + configuracion.tiempo_liberacion as u64, Evento::FinLiberacion(indice));
+                        }
+                    }
+                }
+            }
+
+            if !procesos_cargados.is_empty() {
+                eventos.push(format!(
+                    "En el tiempo global {}, terminó la selección y carga de los procesos: {}. Pasan a la cola de listos.",
+                    tiempo_global,
+                    procesos_cargados.join(", ")
+                ));
+            }
+            if !procesos_desbloqueados.is_empty() {
+                eventos.push(format!(
+                    "En el tiempo global {}, terminaron la E/S los procesos: {}. Pasan a la cola de listos.",
+                    tiempo_global,
+                    procesos_desbloqueados.join(", ")
+                ));
+            }
+            if !procesos_finalizados.is_empty() {
+                eventos.push(format!(
+                    "En el tiempo global {}, finalizaron los procesos: {}. Comienza la liberación de sus particiones.",
+                    tiempo_global,
+                    procesos_finalizados.join(", ")
+                ));
+            }
+            if !procesos_liberados.is_empty() {
+                let memoria_ocupada = memoria.memoria_ocupada();
+                eventos.push(format!(
+                    "En el tiempo global {}, se liberó memoria de los procesos finalizados: {}. (Memoria Ocupada: {} KB de {} total)",
+                    tiempo_global,
+                    procesos_liberados.join(", "),
+                    memoria_ocupada,
+                    memoria.tamanio_total()
+                ));
+                registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                registrar_mapa_memoria(memoria.as_ref(), eventos);
+            }
+            if !procesos_a_swap.is_empty() {
+                eventos.push(format!(
+                    "En el tiempo global {}, terminaron de copiarse al área de swap los procesos: {}. (Memoria Ocupada: {} KB de {} total, área de swap: {} KB de {})",
+                    tiempo_global,
+                    procesos_a_swap.join(", "),
+                    memoria.memoria_ocupada(),
+                    memoria.tamanio_total(),
+                    swap_ocupado,
+                    configuracion.tamanio_swap
+                ));
+                registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                registrar_mapa_memoria(memoria.as_ref(), eventos);
+            }
+
+            if abortada.is_some() {
+                break;
+            }
+
+            // ===========================
+            // Compactación periódica o por umbral de fragmentación
+            // ===========================
+            if tiempo_global >= compactando_hasta {
+                let motivo = match configuracion.compactacion {
+                    Compactacion::Periodica(_) if compactacion_periodica => Some("compactación periódica".to_string()),
+                    Compactacion::UmbralFragmentacion(umbral)
+                        if !procesos_liberados.is_empty()
+                            && compactacion::fragmentacion(&memoria.huecos()) * 100.0 > umbral as f64 =>
+                    {
+                        Some(format!("la fragmentación superó el {}%", umbral))
+                    }
+                    _ => None,
+                };
+                if let Some(motivo) = motivo {
+                    compactando_hasta = tiempo_global + compactar_memoria(
+                        memoria.as_mut(),
+                        configuracion,
+                        &mut cola_eventos,
                         tiempo_global,
-                        procesos_liberados.join(", "),
-                        memoria_ocupada,
-                        configuracion.tamanio_memoria
-                    ));
-                    procesos_liberados.clear();
+                        &motivo,
+                        eventos,
+                        &mut estadisticas_compactacion,
+                    );
                 }
-                hubo_cambio = true;
             }
 
             // ===========================
             // Parte 2: Asignación de procesos
             // ===========================
-            // Intentar asignar procesos que arriban en el tiempo actual
-            while indice_proceso_actual < procesos.len() && procesos[indice_proceso_actual].arribo as u64 <= tiempo_global {
-                let proceso_actual = &procesos[indice_proceso_actual];
-            
-                let proceso_asignado = configuracion.estrategia.asignar_proceso(
-                    &mut vector_memoria,
-                    proceso_actual,
-                    &mut ultima_asignada,
-                );
-            
+            // Intentar asignar los procesos que esperan, en el orden que define la política de admisión
+            let politica = &configuracion.politica_admision;
+            let mut admitidos: Vec<usize> = Vec::new();
+            let mut salteados: Vec<usize> = Vec::new();
+
+            // Mientras la memoria se compacta no se asigna ningún proceso
+            // Antes que nadie, los procesos suspendidos reintentan crecer
+            if tiempo_global >= compactando_hasta {
+                for indice in 0..procesos.len() {
+                    let Some(nuevo) = crecimiento_pendiente[indice] else {
+                        continue;
+                    };
+                    let resultado = memoria.redimensionar(&procesos[indice].redimensionado(nuevo));
+                    if let Redimension::SinLugar = resultado {
+                        continue;
+                    }
+                    registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                    let anterior = memoria_actual[indice];
+                    memoria_actual[indice] = nuevo;
+                    crecimiento_pendiente[indice] = None;
+                    registrar_redimension(
+                        &resultado,
+                        procesos[indice].get_nombre(),
+                        anterior,
+                        nuevo,
+                        tiempo_global,
+                        eventos,
+                        &mut estadisticas_redimension,
+                    );
+                    eventos.push(format!(
+                        "En el tiempo global {}, el proceso {} deja de estar suspendido y vuelve a la cola de listos.",
+                        tiempo_global,
+                        procesos[indice].nombre
+                    ));
+                    registrar_mapa_memoria(memoria.as_ref(), eventos);
+                    estados[indice] = EstadoProceso::Listo;
+                    cola_listos.push(indice);
+                    listo_desde[indice] = tiempo_global;
+                }
+            }
+
+            let candidatos = if tiempo_global >= compactando_hasta {
+                politica.ordenar_candidatos(&cola_espera, procesos, &esperando_desde, tiempo_global)
+            } else {
+                Vec::new()
+            };
+
+            for indice in candidatos {
+                // Ningún proceso puede adelantarse a uno que ya agotó sus salteos
+                if salteados.iter().any(|&s| !politica.permite_saltear(veces_salteado[s])) {
+                    break;
+                }
+                // Un proceso que vuelve del área de swap pide la memoria que tenía al salir
+                let proceso_actual = &procesos[indice].redimensionado(memoria_actual[indice]);
+
+                // La partición queda reservada durante la selección y la carga del proceso.
+                // Un proceso que vuelve del área de swap se carga a la velocidad de transferencia del swap.
+                let vuelve_de_swap = estados[indice] == EstadoProceso::EnSwap;
+                let tiempo_carga = if vuelve_de_swap {
+                    configuracion.tiempo_seleccion
+                        + swapping::tiempo_transferencia(proceso_actual.get_memoria_requerida(), configuracion.tasa_transferencia_swap) as u32
+                } else {
+                    configuracion.tiempo_seleccion + configuracion.tiempo_carga
+                };
+                ciclos_de_vida[indice].primer_intento.get_or_insert(tiempo_global);
+                let mut proceso_asignado = memoria.asignar(proceso_actual, tiempo_carga);
+                if proceso_asignado.is_none() {
+                    fallos_asignacion += 1;
+                    // Fragmentación externa: la memoria libre alcanzaba, pero no en un solo hueco
+                    if memoria.memoria_libre() >= proceso_actual.get_memoria_requerida() {
+                        fallos_con_memoria_suficiente += 1;
+                    }
+                }
+
+                // Si no entra pero la memoria libre total alcanza, se compacta y se vuelve a intentar
+                if proceso_asignado.is_none()
+                    && matches!(configuracion.compactacion, Compactacion::AlFallarAsignacion)
+                    && memoria.memoria_libre() >= proceso_actual.get_memoria_requerida()
+                {
+                    let motivo = format!("el proceso {} no entraba en ningún hueco", proceso_actual.nombre);
+                    compactando_hasta = tiempo_global + compactar_memoria(
+                        memoria.as_mut(),
+                        configuracion,
+                        &mut cola_eventos,
+                        tiempo_global,
+                        &motivo,
+                        eventos,
+                        &mut estadisticas_compactacion,
+                    );
+                    if compactando_hasta > tiempo_global {
+                        // Se reintenta cuando termine la compactación
+                        eventos.push(format!(
+                            "En el tiempo global {}, el proceso {} espera a que termine la compactación.",
+                            tiempo_global,
+                            proceso_actual.nombre
+                        ));
+                        break;
+                    }
+                    proceso_asignado = memoria.asignar(proceso_actual, tiempo_carga);
+                }
+
                 // En la asignación exitosa del proceso
-                if let Some(_) = proceso_asignado {
-                    let memoria_ocupada = calcular_memoria_ocupada(&vector_memoria);
+                if let Some(ubicacion) = proceso_asignado {
+                    admitidos.push(indice);
+                    estados[indice] = EstadoProceso::Cargando;
+                    cargado_en[indice] = tiempo_global;
+                    ejecutado_desde_carga[indice] = 0;
+                    let espera = tiempo_global - esperando_desde[indice];
+                    espera_memoria[indice] += espera;
+                    cola_eventos.cancelar(&Evento::Abandono(indice));
+                    let ciclo = &mut ciclos_de_vida[indice];
+                    if ciclo.asignacion.is_none() {
+                        ciclo.asignacion = Some(tiempo_global);
+                        ciclo.direccion = Some(ubicacion.direccion);
+                        ciclo.tamanio_bloque = Some(ubicacion.tamanio);
+                    }
+                    let nota_swap = if vuelve_de_swap {
+                        let transferencia = (tiempo_carga - configuracion.tiempo_seleccion) as u64;
+                        estadisticas_swap[indice].regresos += 1;
+                        estadisticas_swap[indice].tiempo += transferencia;
+                        swap_ocupado -= proceso_actual.get_memoria_requerida();
+                        format!(" Vuelve desde el área de swap ({} unidades de tiempo de transferencia).", transferencia)
+                    } else {
+                        String::new()
+                    };
+                    registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+
+                    // Los procesos que llegaron antes y siguen esperando fueron salteados
+                    let adelantados: Vec<&str> = cola_espera
+                        .iter()
+                        .take_while(|&&otro| otro != indice)
+                        .filter(|otro| !admitidos.contains(otro))
+                        .map(|&otro| {
+                            veces_salteado[otro] += 1;
+                            procesos[otro].get_nombre()
+                        })
+                        .collect();
+                    adelantamientos_totales += adelantados.len() as u64;
+                    let nota_adelanto = if adelantados.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" Se adelantó a: {}.", adelantados.join(", "))
+                    };
+                    let prioridad = proceso_actual.get_prioridad();
+                    let prioridad_efectiva = politica.prioridad_efectiva(prioridad, espera);
+                    let nota_prioridad = if prioridad_efectiva < prioridad {
+                        format!(" Su prioridad pasó de {} a {} por envejecimiento.", prioridad, prioridad_efectiva)
+                    } else {
+                        String::new()
+                    };
+
+                    let memoria_ocupada = memoria.memoria_ocupada();
                     eventos.push(format!(
-                        "En el tiempo global {}, el proceso {} fue asignado correctamente. (Memoria Ocupada: {} KB de {} total)",
+                        "En el tiempo global {}, el proceso {} fue asignado correctamente en la dirección {} ({} KB). Comenzará a ejecutarse en el tiempo {}.{}{}{} (Memoria Ocupada: {} KB de {} total)",
                         tiempo_global,
                         proceso_actual.nombre,
+                        ubicacion.direccion,
+                        ubicacion.tamanio,
+                        tiempo_global + tiempo_carga as u64,
+                        nota_swap,
+                        nota_adelanto,
+                        nota_prioridad,
                         memoria_ocupada,
-                        configuracion.tamanio_memoria
+                        memoria.tamanio_total()
                     ));
-                    tiempos_de_retorno[indice_proceso_actual] = tiempo_global - proceso_actual.arribo as u64; // Tiempo de retorno inicial
-                    indice_proceso_actual += 1;
-                    hubo_cambio = true;
+                    registrar_mapa_memoria(memoria.as_ref(), eventos);
+                    if tiempo_carga > 0 {
+                        cola_eventos.programar(tiempo_global + tiempo_carga as u64, Evento::FinCarga(indice));
+                    } else {
+                        ciclos_de_vida[indice].fin_carga.get_or_insert(tiempo_global);
+                        estados[indice] = EstadoProceso::Listo;
+                        cola_listos.push(indice);
+                        listo_desde[indice] = tiempo_global;
+                    }
                 } else {
-                    // Si no se pudo asignar, registramos el evento y salimos del bucle
+                    // Si no se pudo asignar, registramos el evento; la política decide si se sigue buscando
+                    registrar_mensajes_memoria(memoria.as_mut(), tiempo_global, eventos);
+                    // Los procesos bloqueados en E/S retienen memoria sin avanzar
+                    let bloqueada = memoria_bloqueada(&memoria_actual, &estados);
+                    if bloqueada > 0 {
+                        fallos_con_bloqueados += 1;
+                    }
                     eventos.push(format!(
-                        "En el tiempo global {}, la memoria estaba llena o era insuficiente para asignar el proceso {}. El proceso quedó esperando.",
+                        "En el tiempo global {}, la memoria estaba llena o era insuficiente para asignar el proceso {}. El proceso quedó esperando.{}",
                         tiempo_global,
-                        proceso_actual.nombre
+                        proceso_actual.nombre,
+                        if bloqueada > 0 {
+                            format!(" ({} KB están ocupados por procesos bloqueados en E/S)", bloqueada)
+                        } else {
+                            String::new()
+                        }
                     ));
-                    hubo_cambio = true; // Registramos que hubo un intento de asignación
-                    break; // Salimos del bucle para reintentar en el siguiente ciclo
+                    salteados.push(indice);
+
+                    // Si hay swapping, se llevan al área de swap procesos en ejecución para hacerle lugar.
+                    // Cada espera hace lugar una sola vez: si otro ocupa la memoria liberada, el proceso
+                    // sigue esperando sin desalojar a nadie más.
+                    if !matches!(configuracion.swapping, PoliticaSwapping::Deshabilitado)
+                        && salidas_a_swap_en_curso == 0
+                        && !desalojo_pedido[indice]
+                    {
+                        // Para evitar que los procesos se desalojen entre sí sin avanzar, una víctima tiene que
+                        // haber usado la CPU desde que se cargó (al menos la residencia mínima, y nunca menos
+                        // de una unidad) y haberse cargado antes de que el proceso empezara a esperar: un
+                        // proceso que vuelve del swap no puede desalojar al que ocupó su lugar. Con desalojo
+                        // por prioridad, sólo pueden ser víctimas los procesos de menor prioridad que el que espera.
+                        let mut residentes: Vec<Residente> = (0..procesos.len())
+                            .filter(|&otro| {
+                                matches!(estados[otro], EstadoProceso::Listo | EstadoProceso::Ejecutando)
+                                    && ejecutado_desde_carga[otro] >= configuracion.residencia_minima_swap.max(1)
+                                    && cargado_en[otro] < esperando_desde[indice]
+                                    && (!configuracion.desalojo_por_prioridad
+                                        || procesos[otro].get_prioridad() > proceso_actual.get_prioridad())
+                            })
+                            .map(|otro| Residente {
+                                indice: otro,
+                                cargado_en: cargado_en[otro],
+                                memoria: memoria_actual[otro],
+                                tiempo_restante: if cpu.en_cpu == Some(otro) {
+                                    restante_en_cpu(&cola_eventos, &cpu, tiempo_global, &tiempo_restante)
+                                } else {
+                                    tiempo_restante[otro]
+                                },
+                            })
+                            .collect();
+                        let candidatos = residentes.len();
+                        configuracion.swapping.ordenar_victimas(&mut residentes);
+                        if configuracion.desalojo_por_prioridad {
+                            // El orden es estable: entre los de igual prioridad decide la política de swapping
+                            residentes.sort_by_key(|r| std::cmp::Reverse(procesos[r.indice].get_prioridad()));
+                        }
+
+                        // Se eligen víctimas hasta liberar lo que necesita el proceso, si el área de swap alcanza
+                        let mut victimas: Vec<usize> = Vec::new();
+                        let mut memoria_a_liberar = memoria.memoria_libre();
+                        let mut swap_necesario = swap_ocupado;
+                        for residente in residentes {
+                            if memoria_a_liberar >= proceso_actual.get_memoria_requerida() {
+                                break;
+                            }
+                            if swap_necesario + residente.memoria <= configuracion.tamanio_swap {
+                                memoria_a_liberar += residente.memoria;
+                                swap_necesario += residente.memoria;
+                                victimas.push(residente.indice);
+                            }
+                        }
+
+                        if memoria_a_liberar >= proceso_actual.get_memoria_requerida() {
+                            desalojo_pedido[indice] = true;
+                            for victima in victimas {
+                                let proceso_victima = &procesos[victima];
+                                // La víctima deja la CPU o la cola de listos
+                                if cpu.en_cpu == Some(victima) {
+                                    interrumpir(&mut cola_eventos, &mut cpu, tiempo_global, &mut tiempo_restante);
+                                } else {
+                                    cola_listos.retain(|&listo| listo != victima);
+                                    espera_cpu[victima] += tiempo_global - listo_desde[victima];
+                                }
+                                let transferencia = swapping::tiempo_transferencia(
+                                    memoria_actual[victima],
+                                    configuracion.tasa_transferencia_swap,
+                                );
+                                memoria.cambiar_estado(proceso_victima.get_nombre(), EstadoParticion::Liberando);
+                                estados[victima] = EstadoProceso::SaliendoASwap;
+                                swap_ocupado += memoria_actual[victima];
+                                salidas_a_swap_en_curso += 1;
+                                estadisticas_swap[victima].salidas += 1;
+                                estadisticas_swap[victima].tiempo += transferencia;
+                                cola_eventos.programar(tiempo_global + transferencia, Evento::FinSwapOut(victima));
+                                eventos.push(format!(
+                                    "En el tiempo global {}, el proceso {} se lleva al área de swap para hacer lugar a {} ({:?}{}). Le quedan {} unidades de ejecución; la transferencia dura {} unidades de tiempo.",
+                                    tiempo_global,
+                                    proceso_victima.nombre,
+                                    proceso_actual.nombre,
+                                    configuracion.swapping,
+                                    if configuracion.desalojo_por_prioridad {
+                                        format!(
+                                            ", desalojo por prioridad: {} contra {}",
+                                            proceso_victima.get_prioridad(),
+                                            proceso_actual.get_prioridad()
+                                        )
+                                    } else {
+                                        String::new()
+                                    },
+                                    tiempo_restante[victima],
+                                    transferencia
+                                ));
+                            }
+                        } else {
+                            estadisticas_swap[indice].sin_victimas += 1;
+                            eventos.push(format!(
+                                "En el tiempo global {}, no hay procesos que puedan llevarse al área de swap para hacerle lugar a {} ({}).",
+                                tiempo_global,
+                                proceso_actual.nombre,
+                                if candidatos == 0 {
+                                    "ningún proceso en memoria cumple las condiciones para ser víctima".to_string()
+                                } else {
+                                    format!("los {} procesos que podrían ser víctimas no liberan lo suficiente o no entran en el área de swap", candidatos)
+                                }
+                            ));
+                        }
+                    }
                 }
             }
+            cola_espera.retain(|indice| !admitidos.contains(indice));
+            cola_espera_maxima = cola_espera_maxima.max(cola_espera.len());
 
             // ===========================
-            // Parte 3: Avance del tiempo
+            // Parte 3: Planificación de la CPU
             // ===========================
-            if !hubo_cambio {
-                eventos.push(format!(
-                    "En el tiempo global {}, no se asignó ni liberó nada. (Memoria Ocupada: {} KB de {} total)",
-                    tiempo_global,
-                    calcular_memoria_ocupada(&vector_memoria),
-                    configuracion.tamanio_memoria
-                ));
-            }
+            // Mientras se compacta la memoria no se despacha ningún proceso
+            if tiempo_global >= compactando_hasta {
+                // Con SRTF, un proceso listo más corto desaloja al que tiene la CPU
+                if let (Some(actual), Some(posicion)) = (cpu.en_cpu, planificacion.elegir(&cola_listos, &tiempo_restante)) {
+                    let candidato = cola_listos[posicion];
+                    let restante_actual = restante_en_cpu(&cola_eventos, &cpu, tiempo_global, &tiempo_restante);
+                    if planificacion.desaloja(tiempo_restante[candidato], restante_actual) {
+                        interrumpir(&mut cola_eventos, &mut cpu, tiempo_global, &mut tiempo_restante);
+                        estados[actual] = EstadoProceso::Listo;
+                        cola_listos.push(actual);
+                        listo_desde[actual] = tiempo_global;
+                        eventos.push(format!(
+                            "En el tiempo global {}, el proceso {} desaloja de la CPU al proceso {}, al que le quedan {} unidades de ejecución.",
+                            tiempo_global,
+                            procesos[candidato].nombre,
+                            procesos[actual].nombre,
+                            tiempo_restante[actual]
+                        ));
+                    }
+                }
 
-            // Decrementar tiempo de vida de los procesos en memoria
-            decrementar_tiempo_vida(&mut vector_memoria);
+                if cpu.en_cpu.is_none() {
+                    if let Some(posicion) = planificacion.elegir(&cola_listos, &tiempo_restante) {
+                        let indice = cola_listos.remove(posicion);
+                        estados[indice] = EstadoProceso::Ejecutando;
+                        espera_cpu[indice] += tiempo_global - listo_desde[indice];
+                        primera_ejecucion[indice].get_or_insert(tiempo_global);
+                        cpu.despachos += 1;
+                        programar_rafaga(&mut cola_eventos, &mut cpu, planificacion, indice, tiempo_global, &tiempo_restante);
+                        programar_cambio_memoria(
+                            &mut cola_eventos,
+                            &procesos[indice],
+                            indice,
+                            siguiente_cambio[indice],
+                            cpu_ejecutada(&rafagas[indice], rafaga_actual[indice], tiempo_restante[indice]),
+                            tiempo_global,
+                        );
+                        eventos.push(format!(
+                            "En el tiempo global {}, el proceso {} toma la CPU. Le quedan {} unidades de ejecución.",
+                            tiempo_global,
+                            procesos[indice].nombre,
+                            tiempo_restante[indice]
+                        ));
+                    }
+                }
+            }
 
-            // Calcular particiones libres para el índice de fragmentación externa
-            let particiones_libres_este_ciclo = vector_memoria.iter().filter(|p| p.esta_libre()).count() as u64;
-            particiones_libres_totales += particiones_libres_este_ciclo;
+            // ===========================
+            // Parte 4: Procesos que ya no pueden avanzar
+            // ===========================
+            // Si no queda ningún evento pendiente, nada va a liberar memoria: los procesos que siguen
+            // esperando (o suspendidos esperando crecer) esperarían para siempre
+            if !cola_eventos.hay_eventos_pendientes() {
+                // Primero los suspendidos, empezando por el que más memoria retiene, y luego la cola de espera
+                let mut suspendidos: Vec<usize> = (0..procesos.len())
+                    .filter(|&i| estados[i] == EstadoProceso::Suspendido)
+                    .collect();
+                suspendidos.sort_by_key(|&i| std::cmp::Reverse(memoria_actual[i]));
+                let atascados: Vec<(usize, String)> = suspendidos
+                    .into_iter()
+                    .map(|i| {
+                        (i, format!(
+                            "quedó suspendido esperando crecer a {} KB y ningún proceso puede liberarle lugar",
+                            crecimiento_pendiente[i].unwrap_or(memoria_actual[i])
+                        ))
+                    })
+                    .chain(
+                        politica
+                            .ordenar_candidatos(&cola_espera, procesos, &esperando_desde, tiempo_global)
+                            .into_iter()
+                            .map(|i| {
+                                (i, format!(
+                                    "espera {} KB y ningún proceso puede liberarle lugar",
+                                    memoria_actual[i]
+                                ))
+                            }),
+                    )
+                    .collect();
 
-            // Avanzar el tiempo global en una unidad
-            tiempo_global += 1;
+                match configuracion.insatisfacibles {
+                    // Se descarta uno por vez: la memoria que libera puede destrabar a los demás
+                    PoliticaInsatisfacibles::Rechazar => {
+                        if let Some((indice, motivo)) = atascados.into_iter().next() {
+                            motivo_rechazo[indice] = Some(motivo);
+                            cola_eventos.programar(tiempo_global, Evento::Rechazo(indice));
+                        }
+                    }
+                    // Ya arribaron todos: truncar la tanda es descartar a todos los que quedan
+                    PoliticaInsatisfacibles::Truncar => {
+                        for (indice, motivo) in atascados {
+                            motivo_rechazo[indice] = Some(motivo);
+                            cola_eventos.programar(tiempo_global, Evento::Rechazo(indice));
+                        }
+                    }
+                    PoliticaInsatisfacibles::Abortar => {
+                        if let Some((indice, motivo)) = atascados.into_iter().next() {
+                            let diagnostico = format!("el proceso {} {}", procesos[indice].nombre, motivo);
+                            eventos.push(format!(
+                                "En el tiempo global {}, se aborta la simulación: {}.",
+                                tiempo_global,
+                                diagnostico
+                            ));
+                            abortada = Some(diagnostico);
+                        }
+                    }
+                }
+            }
         }
 
+        // El último instante con eventos cuenta como una unidad de tiempo completa
+        metricas_memoria.acumular(memoria.as_ref(), 1);
+        memoria.avanzar_tiempo(1);
+        tiempo_global += 1;
+
         // ===========================
         // Registro de resultados finales
         // ===========================
-        // Calcular tiempos de retorno
+        // Calcular tiempos de retorno (incluyen selección, carga y liberación)
+        // Los procesos rechazados o que no llegaron a terminar no cuentan para el promedio
+        // El detalle de cada proceso se escribe como tabla a partir de su ciclo de vida
         let mut tiempos_totales_retorno = 0;
+        let mut terminados = 0;
+        for (i, ciclo) in ciclos_de_vida.iter_mut().enumerate() {
+            ciclo.desenlace = match (estados[i], abandono_en[i]) {
+                (_, Some(instante)) => Desenlace::Abandono(instante),
+                (EstadoProceso::Terminado, _) => Desenlace::Terminado,
+                (EstadoProceso::Rechazado, _) => Desenlace::Rechazado,
+                _ => Desenlace::SinTerminar,
+            };
+            if let Some(tiempo_retorno) = ciclo.retorno() {
+                tiempos_totales_retorno += tiempo_retorno;
+                terminados += 1;
+            }
+        }
+
+        // Registrar los procesos rechazados y, si la hubo, la causa del aborto
+        let rechazados: Vec<String> = (0..procesos.len())
+            .filter_map(|i| motivo_rechazo[i].as_ref().map(|motivo| format!("{} ({})", procesos[i].nombre, motivo)))
+            .collect();
+        if !rechazados.is_empty() {
+            resultados.push(format!(
+                "Procesos rechazados ({:?}): {}.",
+                configuracion.insatisfacibles,
+                rechazados.join("; ")
+            ));
+        }
+        if let Some(diagnostico) = &abortada {
+            resultados.push(format!("Simulación abortada: {}.", diagnostico));
+        }
+
+        // Registrar los abandonos, si la tanda tiene procesos con paciencia
+        let abandonos: Vec<usize> = (0..procesos.len()).filter(|&i| abandono_en[i].is_some()).collect();
+        if procesos.iter().any(|p| p.get_paciencia().is_some()) {
+            resultados.push(format!(
+                "Tasa de abandono con {}: {} de {} procesos ({:.2}%).",
+                configuracion.nombre_estrategia(),
+                abandonos.len(),
+                procesos.len(),
+                abandonos.len() as f64 * 100.0 / procesos.len() as f64
+            ));
+            if !abandonos.is_empty() {
+                resultados.push(format!(
+                    "Tiempo medio en el sistema de los procesos que abandonaron: {:.2} unidades de tiempo.",
+                    abandonos
+                        .iter()
+                        .map(|&i| abandono_en[i].unwrap_or(0) - procesos[i].get_arribo() as u64)
+                        .sum::<u64>() as f64
+                        / abandonos.len() as f64
+                ));
+            }
+        }
+
+        // Registrar la sobrecarga de administración de memoria de cada proceso
+        resultados.push(format!(
+            "Sobrecarga por proceso: {} de selección + {} de carga + {} de liberación = {} unidades de tiempo.",
+            configuracion.tiempo_seleccion,
+            configuracion.tiempo_carga,
+            configuracion.tiempo_liberacion,
+            configuracion.tiempo_seleccion + configuracion.tiempo_carga + configuracion.tiempo_liberacion
+        ));
+
+        // Registrar la política de admisión utilizada
+        resultados.push(format!(
+            "Política de admisión: {:?}. Adelantamientos en la cola de espera: {}.",
+            configuracion.politica_admision,
+            adelantamientos_totales
+        ));
+
+        // Desglosar retorno y esperas por clase de prioridad, si la tanda tiene más de una
+        let mut clases: Vec<u32> = procesos.iter().map(|p| p.get_prioridad()).collect();
+        clases.sort();
+        clases.dedup();
+        if clases.len() > 1 {
+            for clase in clases {
+                let miembros: Vec<usize> = (0..procesos.len()).filter(|&i| procesos[i].get_prioridad() == clase).collect();
+                let media = |valores: &dyn Fn(usize) -> u64| {
+                    miembros.iter().map(|&i| valores(i)).sum::<u64>() as f64 / miembros.len() as f64
+                };
+                // El retorno sólo se promedia entre los que terminaron
+                let retornos: Vec<u64> = miembros.iter().filter_map(|&i| ciclos_de_vida[i].retorno()).collect();
+                let retorno_medio = if retornos.is_empty() {
+                    0.0
+                } else {
+                    retornos.iter().sum::<u64>() as f64 / retornos.len() as f64
+                };
+                resultados.push(format!(
+                    "Prioridad {}: {} procesos. Tiempo medio de retorno: {:.2}. Tiempo medio de espera de memoria: {:.2}. Tiempo medio de espera en CPU: {:.2}.",
+                    clase,
+                    miembros.len(),
+                    retorno_medio,
+                    media(&|i| espera_memoria[i]),
+                    media(&|i| espera_cpu[i])
+                ));
+            }
+        }
+
+        // Registrar las compactaciones realizadas
+        resultados.push(format!(
+            "Compactación: {:?}. Compactaciones realizadas: {}. KB movidos: {}. Tiempo total de compactación: {} unidades de tiempo.",
+            configuracion.compactacion,
+            estadisticas_compactacion.cantidad,
+            estadisticas_compactacion.kb_movidos,
+            estadisticas_compactacion.tiempo_total
+        ));
+
+        // Registrar la planificación de la CPU
         for (i, proceso) in procesos.iter().enumerate() {
-            let tiempo_retorno = tiempos_de_retorno[i] + proceso.duracion as u64;
             resultados.push(format!(
-                "Tiempo de retorno del proceso {}: {} unidades de tiempo.",
+                "Tiempo de espera en CPU del proceso {}: {} unidades de tiempo. Tiempo de respuesta: {}.",
                 proceso.nombre,
-                tiempo_retorno
+                espera_cpu[i],
+                primera_ejecucion[i].map_or("no llegó a ejecutarse".to_string(), |inicio| format!(
+                    "{} unidades de tiempo",
+                    inicio - proceso.get_arribo() as u64
+                ))
             ));
-            tiempos_totales_retorno += tiempo_retorno;
         }
+        let ejecutados: Vec<usize> = (0..procesos.len()).filter(|&i| primera_ejecucion[i].is_some()).collect();
+        let (espera_media, respuesta_media) = if ejecutados.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (
+                ejecutados.iter().map(|&i| espera_cpu[i]).sum::<u64>() as f64 / ejecutados.len() as f64,
+                ejecutados
+                    .iter()
+                    .map(|&i| primera_ejecucion[i].unwrap_or(0) - procesos[i].get_arribo() as u64)
+                    .sum::<u64>() as f64
+                    / ejecutados.len() as f64,
+            )
+        };
+        resultados.push(format!(
+            "Planificación de CPU: {:?}. Despachos: {}. Tiempo medio de espera en CPU: {:.2}. Tiempo medio de respuesta: {:.2}. Utilización de la CPU: {:.2}%.",
+            planificacion,
+            cpu.despachos,
+            espera_media,
+            respuesta_media,
+            cpu.tiempo_ocupada as f64 * 100.0 / tiempo_global as f64
+        ));
+
+        // Registrar las E/S, si la tanda tiene procesos con ráfagas de E/S
+        if procesos.iter().any(|p| !p.rafagas.is_empty()) {
+            for (i, proceso) in procesos.iter().enumerate().filter(|(_, p)| !p.rafagas.is_empty()) {
+                resultados.push(format!(
+                    "E/S del proceso {}: {} ráfagas de E/S, {} unidades de tiempo bloqueado.",
+                    proceso.nombre,
+                    rafagas[i].iter().filter(|r| matches!(r, Rafaga::EntradaSalida { .. })).count(),
+                    tiempo_bloqueado[i]
+                ));
+            }
+            for (numero, dispositivo) in dispositivos.iter().enumerate() {
+                resultados.push(format!(
+                    "Dispositivo de E/S {}: {} atenciones. Utilización: {:.2}%. Cola máxima: {} procesos.",
+                    numero,
+                    dispositivo.atenciones,
+                    dispositivo.tiempo_ocupado as f64 * 100.0 / tiempo_global as f64,
+                    dispositivo.cola_maxima
+                ));
+            }
+            resultados.push(format!(
+                "Memoria media retenida por procesos bloqueados en E/S: {:.2} KB. Asignaciones fallidas con procesos bloqueados en memoria: {}.",
+                memoria_bloqueada_acumulada as f64 / tiempo_global as f64,
+                fallos_con_bloqueados
+            ));
+        }
+
+        // Registrar los cambios de tamaño, si la tanda tiene procesos que piden memoria al ejecutar
+        if procesos.iter().any(|p| !p.cambios_memoria.is_empty()) {
+            resultados.push(format!(
+                "Cambios de tamaño: {} crecimientos en el lugar, {} crecimientos con reubicación, {} reducciones. Suspensiones por falta de lugar: {}.",
+                estadisticas_redimension.en_lugar,
+                estadisticas_redimension.reubicados,
+                estadisticas_redimension.reducciones,
+                estadisticas_redimension.suspensiones
+            ));
+            let suspendidos: Vec<&str> = (0..procesos.len())
+                .filter(|&i| estados[i] == EstadoProceso::Suspendido)
+                .map(|i| procesos[i].get_nombre())
+                .collect();
+            if !suspendidos.is_empty() {
+                resultados.push(format!(
+                    "Procesos que terminaron la simulación suspendidos (su crecimiento nunca entró): {}.",
+                    suspendidos.join(", ")
+                ));
+            }
+        }
+
+        // Registrar el swapping realizado por cada proceso
+        if !matches!(configuracion.swapping, PoliticaSwapping::Deshabilitado) {
+            resultados.push(format!(
+                "Swapping: {:?}. Área de swap de {} KB, {} KB por unidad de tiempo, residencia mínima de {} unidades de CPU. Salidas a swap: {}. Tiempo total de swapping: {} unidades de tiempo.",
+                configuracion.swapping,
+                configuracion.tamanio_swap,
+                configuracion.tasa_transferencia_swap,
+                configuracion.residencia_minima_swap,
+                estadisticas_swap.iter().map(|e| e.salidas).sum::<u32>(),
+                estadisticas_swap.iter().map(|e| e.tiempo).sum::<u64>()
+            ));
+            resultados.push(format!(
+                "Intentos de swapping sin víctimas que alcanzaran: {}.",
+                estadisticas_swap.iter().map(|e| e.sin_victimas).sum::<u32>()
+            ));
+            for (proceso, estadistica) in procesos.iter().zip(estadisticas_swap.iter()) {
+                resultados.push(format!(
+                    "Swapping del proceso {}: {} salidas, {} regresos, {} unidades de tiempo de transferencia, {} veces sin víctimas para hacerle lugar.",
+                    proceso.nombre,
+                    estadistica.salidas,
+                    estadistica.regresos,
+                    estadistica.tiempo,
+                    estadistica.sin_victimas
+                ));
+            }
+        }
+
+        // Registrar los resultados propios del modelo de memoria
+        resultados.push(format!("Modelo de memoria: {:?}.", configuracion.modo_memoria));
+        resultados.extend(memoria.resumen(tiempo_global));
 
         // Calcular tiempo medio de retorno
-        let tiempo_medio_retorno = tiempos_totales_retorno as f64 / procesos.len() as f64;
+        let tiempo_medio_retorno = if terminados == 0 {
+            0.0
+        } else {
+            tiempos_totales_retorno as f64 / terminados as f64
+        };
         resultados.push(format!(
             "Tiempo medio de retorno: {:.2} unidades de tiempo.",
             tiempo_medio_retorno
         ));
 
-        // Calcular índice de fragmentación externa
-        let indice_fragmentacion_externa = particiones_libres_totales as f64 / tiempo_global as f64;
+        // Métricas de la memoria ponderadas por el tiempo
+        resultados.push(format!(
+            "Utilización media de la memoria: {:.2}% ({:.2} KB ocupados en promedio).",
+            metricas_memoria.media(metricas_memoria.ocupada as f64) * 100.0 / memoria.tamanio_total() as f64,
+            metricas_memoria.media(metricas_memoria.ocupada as f64)
+        ));
+        resultados.push(format!(
+            "Memoria libre media: {:.2} KB.",
+            metricas_memoria.media(metricas_memoria.libre as f64)
+        ));
+        resultados.push(format!(
+            "Huecos libres: {:.2} en promedio, {} como máximo. Hueco más grande medio: {:.2} KB.",
+            metricas_memoria.media(metricas_memoria.huecos as f64),
+            metricas_memoria.huecos_maximo,
+            metricas_memoria.media(metricas_memoria.hueco_mas_grande as f64)
+        ));
+        resultados.push(format!(
+            "Fragmentación externa media (1 - hueco más grande / memoria libre): {:.2}%.",
+            metricas_memoria.media(metricas_memoria.fragmentacion) * 100.0
+        ));
         resultados.push(format!(
-            "Índice de fragmentación externa: {:.2}.",
-            indice_fragmentacion_externa
+            "Asignaciones fallidas: {}, de las cuales {} tenían memoria libre suficiente en total (fragmentación externa).",
+            fallos_asignacion, fallos_con_memoria_suficiente
+        ));
+        if let Some(busquedas) = memoria.estadisticas_busqueda() {
+            resultados.push(format!(
+                "Costo de búsqueda con {}: {} búsquedas de hueco. Longitud media de búsqueda: {:.2} particiones inspeccionadas ({:.2}% de las particiones en memoria). Huecos candidatos comparados: {} en total, {:.2} por búsqueda.",
+                configuracion.nombre_estrategia(),
+                busquedas.busquedas,
+                busquedas.longitud_media(),
+                busquedas.porcentaje_recorrido(),
+                busquedas.comparadas,
+                busquedas.comparadas_media()
+            ));
+        }
+        resultados.push(format!(
+            "Longitud máxima de la cola de espera de memoria: {} procesos.",
+            cola_espera_maxima
         ));
 
         // Registrar tiempo total de simulación
@@ -141,6 +1401,23 @@ impl Simulador {
             "Tiempo total de la simulación: {} unidades de tiempo.",
             tiempo_global
         ));
+
+        if let Some(diagnostico) = abortada {
+            EstadoSimulacion::Incompleta(format!("simulación abortada: {}", diagnostico))
+        } else if !rechazados.is_empty() {
+            EstadoSimulacion::Incompleta(if abandonos.is_empty() {
+                format!("{} de {} procesos rechazados", rechazados.len(), procesos.len())
+            } else {
+                format!(
+                    "{} de {} procesos rechazados, {} por abandono",
+                    rechazados.len(),
+                    procesos.len(),
+                    abandonos.len()
+                )
+            })
+        } else {
+            EstadoSimulacion::Completa
+        }
     }
 }
 
@@ -148,73 +1425,370 @@ impl Simulador {
 // Definición de funciones
 // ===========================
 
-/// Función para determinar si la tanda ha finalizado
-/// La tanda ha finalizado si todos los procesos han sido asignados y la memoria está completamente libre.
-fn fin_de_la_tanda(indice_proceso_actual: usize, total_procesos: usize, vector_memoria: &[Particion]) -> bool {
-    indice_proceso_actual == total_procesos && vector_memoria.iter().all(|p| p.esta_libre())
-}
-
-/// Funcion para crear el vector del simulador con la configuracion seteada
-/// Crea un vector de particiones, todas inicialmente libres, con el tamaño especificado.
-fn crear_vector_memoria(tamanio_memoria: u32) -> Vec<Particion> {
-    let mut vector_memoria = Vec::new();
-    for _ in 0..tamanio_memoria {
-        let particion = Particion::nueva(); // Crear una nueva partición de tamaño 1KB
-        vector_memoria.push(particion); // Añadir la partición al vector de memoria
-    }
-    vector_memoria
-}
-
-/// Funcion para liberar memoria de los procesos que han finalizado
-/// Libera la memoria de los procesos cuyo tiempo de vida ha llegado a cero.
-fn liberar_memoria(
-    vector_memoria: &mut Vec<Particion>,
-    _configuracion: &Config,
-    procesos_liberados: &mut Vec<String>,
-) -> bool {
-    let mut memoria_liberada = false;
-    let mut procesos_a_liberar = Vec::new();
-
-    // Identificar procesos que deben ser liberados
-    for particion in vector_memoria.iter() {
-        if let Some(tiempo_vida) = particion.tiempo_de_vida {
-            if tiempo_vida == 0 {
-                if let Some(ref nombre_proceso) = particion.id_proceso {
-                    if !procesos_a_liberar.contains(nombre_proceso) {
-                        procesos_a_liberar.push(nombre_proceso.clone());
-                    }
-                }
-            }
+/// Corta la tanda: rechaza a los procesos desde `desde` que todavía no arribaron
+fn truncar_tanda(
+    cola_eventos: &mut ColaEventos,
+    procesos: &[Proceso],
+    desde: usize,
+    estados: &mut [EstadoProceso],
+    motivo_rechazo: &mut [Option<String>],
+    eventos: &mut Vec<String>,
+) {
+    let mut descartados: Vec<&str> = Vec::new();
+    for indice in desde..procesos.len() {
+        if cola_eventos.cancelar(&Evento::LlegadaProceso(indice)).is_some() {
+            estados[indice] = EstadoProceso::Rechazado;
+            motivo_rechazo[indice] = Some("la tanda se truncó antes de su arribo".to_string());
+            descartados.push(procesos[indice].get_nombre());
         }
     }
+    if !descartados.is_empty() {
+        eventos.push(format!(
+            "Se trunca la tanda: se descartan los procesos que todavía no arribaron: {}.",
+            descartados.join(", ")
+        ));
+    }
+}
 
-    // Liberar particiones de los procesos identificados
-    for particion in vector_memoria.iter_mut() {
-        if let Some(ref nombre_proceso) = particion.id_proceso {
-            if procesos_a_liberar.contains(nombre_proceso) {
-                particion.liberar();
-                memoria_liberada = true;
-            }
+/// Registra como eventos los mensajes pendientes del modelo de memoria
+fn registrar_mensajes_memoria(memoria: &mut dyn ModeloMemoria, tiempo_global: u64, eventos: &mut Vec<String>) {
+    for mensaje in memoria.tomar_mensajes() {
+        eventos.push(format!("En el tiempo global {}, {}.", tiempo_global, mensaje));
+    }
+}
+
+/// Registra una instantánea del estado de la memoria, si el modelo la ofrece
+fn registrar_mapa_memoria(memoria: &dyn ModeloMemoria, eventos: &mut Vec<String>) {
+    if let Some(mapa) = memoria.mapa() {
+        eventos.push(format!("    {}", mapa));
+    }
+}
+
+/// Estado del único procesador
+#[derive(Debug, Default)]
+struct EstadoCpu {
+    en_cpu: Option<usize>, // Proceso que tiene la CPU
+    rafaga: u64,           // Duración de la ráfaga que se le concedió al tomar la CPU
+    tiempo_ocupada: u64,
+    despachos: u32,
+}
+
+/// Le concede la CPU al proceso por una ráfaga: hasta terminar o, con Round-Robin, hasta agotar el quantum
+fn programar_rafaga(
+    cola_eventos: &mut ColaEventos,
+    cpu: &mut EstadoCpu,
+    planificacion: &PlanificacionCpu,
+    indice: usize,
+    tiempo_global: u64,
+    tiempo_restante: &[u64],
+) {
+    cpu.en_cpu = Some(indice);
+    cpu.rafaga = planificacion.rafaga(tiempo_restante[indice]);
+    if cpu.rafaga == tiempo_restante[indice] {
+        cola_eventos.programar(tiempo_global + cpu.rafaga, Evento::FinProceso(indice));
+    } else {
+        cola_eventos.programar(tiempo_global + cpu.rafaga, Evento::FinQuantum(indice));
+    }
+}
+
+/// Tiempo de ejecución que le queda al proceso que tiene la CPU, descontando lo que ya ejecutó de su ráfaga
+fn restante_en_cpu(cola_eventos: &ColaEventos, cpu: &EstadoCpu, tiempo_global: u64, tiempo_restante: &[u64]) -> u64 {
+    let Some(indice) = cpu.en_cpu else {
+        return 0;
+    };
+    let fin = cola_eventos
+        .tiempo_de(&Evento::FinProceso(indice))
+        .or_else(|| cola_eventos.tiempo_de(&Evento::FinQuantum(indice)))
+        .unwrap_or(tiempo_global);
+    let ejecutado = cpu.rafaga.saturating_sub(fin - tiempo_global);
+    tiempo_restante[indice] - ejecutado
+}
+
+/// Saca de la CPU al proceso que la tiene: cancela el fin de su ráfaga y descuenta lo que llegó a ejecutar
+fn interrumpir(cola_eventos: &mut ColaEventos, cpu: &mut EstadoCpu, tiempo_global: u64, tiempo_restante: &mut [u64]) {
+    let Some(indice) = cpu.en_cpu else {
+        return;
+    };
+    tiempo_restante[indice] = restante_en_cpu(cola_eventos, cpu, tiempo_global, tiempo_restante);
+    cola_eventos.cancelar(&Evento::FinProceso(indice));
+    cola_eventos.cancelar(&Evento::FinQuantum(indice));
+    cola_eventos.cancelar(&Evento::CambioMemoria(indice));
+    cpu.en_cpu = None;
+}
+
+/// Memoria retenida por los procesos bloqueados en E/S, en KB
+fn memoria_bloqueada(memoria_actual: &[u32], estados: &[EstadoProceso]) -> u32 {
+    memoria_actual
+        .iter()
+        .zip(estados.iter())
+        .filter(|(_, &estado)| estado == EstadoProceso::Bloqueado)
+        .map(|(memoria, _)| memoria)
+        .sum()
+}
+
+/// Unidades de CPU que lleva ejecutadas un proceso, según lo que le queda de su ráfaga actual
+fn cpu_ejecutada(rafagas: &[Rafaga], rafaga_actual: usize, restante: u64) -> u64 {
+    let hasta_la_actual: u64 = rafagas
+        .iter()
+        .take(rafaga_actual + 1)
+        .map(|r| match r {
+            Rafaga::Cpu(duracion) => *duracion,
+            Rafaga::EntradaSalida { .. } => 0,
+        })
+        .sum();
+    hasta_la_actual - restante
+}
+
+/// Si el próximo pedido de memoria del proceso que tiene la CPU cae antes del fin de su ráfaga, lo programa
+fn programar_cambio_memoria(
+    cola_eventos: &mut ColaEventos,
+    proceso: &Proceso,
+    indice: usize,
+    siguiente: usize,
+    ejecutada: u64,
+    tiempo_global: u64,
+) {
+    let Some(cambio) = proceso.cambios_memoria.get(siguiente) else {
+        return;
+    };
+    let fin_rafaga = cola_eventos
+        .tiempo_de(&Evento::FinProceso(indice))
+        .or_else(|| cola_eventos.tiempo_de(&Evento::FinQuantum(indice)))
+        .unwrap_or(tiempo_global);
+    let instante = tiempo_global + cambio.tras_ejecutar.saturating_sub(ejecutada);
+    if instante < fin_rafaga {
+        cola_eventos.programar(instante, Evento::CambioMemoria(indice));
+    }
+}
+
+/// Acumulado de los cambios de tamaño de los procesos
+#[derive(Debug, Default)]
+struct EstadisticasRedimension {
+    en_lugar: u32,
+    reubicados: u32,
+    reducciones: u32,
+    suspensiones: u32,
+}
+
+/// Registra un cambio de tamaño que pudo hacerse, según cómo se hizo
+fn registrar_redimension(
+    resultado: &Redimension,
+    nombre: &str,
+    anterior: u32,
+    nuevo: u32,
+    tiempo_global: u64,
+    eventos: &mut Vec<String>,
+    estadisticas: &mut EstadisticasRedimension,
+) {
+    match resultado {
+        Redimension::EnLugar if nuevo >= anterior => {
+            estadisticas.en_lugar += 1;
+            eventos.push(format!(
+                "En el tiempo global {}, el proceso {} creció en el lugar de {} a {} KB.",
+                tiempo_global, nombre, anterior, nuevo
+            ));
+        }
+        Redimension::EnLugar => {
+            estadisticas.reducciones += 1;
+            eventos.push(format!(
+                "En el tiempo global {}, el proceso {} devolvió memoria: pasó de {} a {} KB.",
+                tiempo_global, nombre, anterior, nuevo
+            ));
+        }
+        Redimension::Reubicado(ubicacion) => {
+            estadisticas.reubicados += 1;
+            eventos.push(format!(
+                "En el tiempo global {}, el proceso {} no podía crecer en el lugar: se reubicó en la dirección {} para pasar de {} a {} KB.",
+                tiempo_global, nombre, ubicacion.direccion, anterior, nuevo
+            ));
+        }
+        Redimension::SinLugar => {}
+    }
+}
+
+/// Dispositivo de E/S: atiende a un proceso por vez y los demás esperan en su cola
+#[derive(Debug, Clone, Default)]
+struct Dispositivo {
+    atendiendo: Option<usize>,
+    cola: Vec<(usize, u64)>, // Procesos que esperan el dispositivo, con la duración de su E/S
+    tiempo_ocupado: u64,
+    atenciones: u32,
+    cola_maxima: usize,
+}
+
+impl Dispositivo {
+    /// Un proceso pide el dispositivo: lo atiende si está libre o lo pone en la cola
+    fn solicitar(&mut self, indice: usize, duracion: u64, cola_eventos: &mut ColaEventos, tiempo_global: u64) {
+        if self.atendiendo.is_none() {
+            self.atender(indice, duracion, cola_eventos, tiempo_global);
+        } else {
+            self.cola.push((indice, duracion));
+            self.cola_maxima = self.cola_maxima.max(self.cola.len());
+        }
+    }
+
+    /// Comienza la E/S de un proceso y programa su fin
+    fn atender(&mut self, indice: usize, duracion: u64, cola_eventos: &mut ColaEventos, tiempo_global: u64) {
+        self.atendiendo = Some(indice);
+        self.atenciones += 1;
+        cola_eventos.programar(tiempo_global + duracion, Evento::FinEntradaSalida(indice));
+    }
+}
+
+/// Acumulado del swapping de un proceso
+#[derive(Debug, Clone, Default)]
+struct EstadisticasSwap {
+    salidas: u32,
+    regresos: u32,
+    tiempo: u64, // Tiempo de transferencia, de ida y de vuelta
+    sin_victimas: u32, // Veces que esperó memoria y el swapping no encontró a quién desalojar
+}
+
+/// Estado de la memoria acumulado por unidad de tiempo, para calcular promedios ponderados
+#[derive(Debug, Default)]
+struct MetricasMemoria {
+    tiempo: u64,
+    ocupada: u64,          // KB ocupados por unidad de tiempo
+    libre: u64,            // KB libres por unidad de tiempo
+    huecos: u64,           // Cantidad de huecos por unidad de tiempo
+    huecos_maximo: usize,
+    hueco_mas_grande: u64, // KB del hueco más grande por unidad de tiempo
+    fragmentacion: f64,    // 1 - hueco más grande / memoria libre, por unidad de tiempo
+}
+
+impl MetricasMemoria {
+    /// Suma el estado actual de la memoria, que se mantuvo durante `duracion` unidades de tiempo
+    fn acumular(&mut self, memoria: &dyn ModeloMemoria, duracion: u64) {
+        let huecos = memoria.huecos();
+        self.tiempo += duracion;
+        self.ocupada += memoria.memoria_ocupada() as u64 * duracion;
+        self.libre += memoria.memoria_libre() as u64 * duracion;
+        self.huecos += huecos.len() as u64 * duracion;
+        self.huecos_maximo = self.huecos_maximo.max(huecos.len());
+        self.hueco_mas_grande += huecos.iter().copied().max().unwrap_or(0) as u64 * duracion;
+        self.fragmentacion += compactacion::fragmentacion(&huecos) * duracion as f64;
+    }
+
+    /// Promedio en el tiempo de una de las magnitudes acumuladas
+    fn media(&self, acumulado: f64) -> f64 {
+        if self.tiempo == 0 {
+            0.0
+        } else {
+            acumulado / self.tiempo as f64
         }
     }
+}
 
-    procesos_liberados.extend(procesos_a_liberar);
-    memoria_liberada
+/// Acumulado de las compactaciones de una simulación
+struct EstadisticasCompactacion {
+    cantidad: u32,
+    tiempo_total: u64,
+    kb_movidos: u64,
 }
 
-/// Función para calcular la memoria ocupada
-/// Devuelve la cantidad de memoria ocupada en KB
-fn calcular_memoria_ocupada(vector_memoria: &[Particion]) -> u32 {
-    vector_memoria.iter().filter(|p| !p.esta_libre()).count() as u32
+/// Función para compactar la memoria y cobrar su costo en el reloj
+/// La reubicación cuesta `tiempo_reubicacion_kb` por cada KB movido; durante ese tiempo los
+/// procesos residentes quedan detenidos. Devuelve la duración de la compactación.
+fn compactar_memoria(
+    memoria: &mut dyn ModeloMemoria,
+    configuracion: &Config,
+    cola_eventos: &mut ColaEventos,
+    tiempo_global: u64,
+    motivo: &str,
+    eventos: &mut Vec<String>,
+    estadisticas: &mut EstadisticasCompactacion,
+) -> u64 {
+    let kb_movidos = memoria.compactar();
+    if kb_movidos == 0 {
+        return 0; // La memoria ya estaba compactada
+    }
+
+    let duracion = kb_movidos as u64 * configuracion.tiempo_reubicacion_kb as u64;
+    estadisticas.cantidad += 1;
+    estadisticas.tiempo_total += duracion;
+    estadisticas.kb_movidos += kb_movidos as u64;
+
+    eventos.push(format!(
+        "En el tiempo global {}, se compactó la memoria ({}): se movieron {} KB. La compactación dura {} unidades de tiempo. (Memoria Ocupada: {} KB de {} total)",
+        tiempo_global,
+        motivo,
+        kb_movidos,
+        duracion,
+        memoria.memoria_ocupada(),
+        memoria.tamanio_total()
+    ));
+    registrar_mapa_memoria(memoria, eventos);
+
+    if duracion > 0 {
+        cola_eventos.postergar_residentes(duracion);
+        cola_eventos.programar(tiempo_global + duracion, Evento::FinCompactacion);
+    }
+    duracion
 }
 
-//Esta función se encarga de reducir el tiempo de vida restante de los procesos en memoria en cada ciclo.
-fn decrementar_tiempo_vida(vector_memoria: &mut Vec<Particion>) {
-    for particion in vector_memoria.iter_mut() {
-        if let Some(tiempo_vida) = particion.tiempo_de_vida {
-            if tiempo_vida > 0 {
-                particion.tiempo_de_vida = Some(tiempo_vida - 1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::admision::PoliticaAdmision;
+    use crate::estrategias::FirstFit;
+    use crate::memoria::ModoMemoria;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    fn configuracion(swapping: PoliticaSwapping, residencia_minima_swap: u64) -> Config {
+        Config {
+            estrategia: Box::new(FirstFit),
+            tamanio_memoria: 300,
+            modo_memoria: ModoMemoria::ParticionesDinamicas,
+            tiempo_seleccion: 0,
+            tiempo_carga: 0,
+            tiempo_liberacion: 0,
+            politica_admision: PoliticaAdmision::Fifo,
+            desalojo_por_prioridad: false,
+            compactacion: Compactacion::Deshabilitada,
+            tiempo_reubicacion_kb: 0,
+            swapping,
+            tamanio_swap: 1000,
+            tasa_transferencia_swap: 1000,
+            residencia_minima_swap,
+            planificacion: PlanificacionCpu::Fcfs,
+            dispositivos_es: 1,
+            insatisfacibles: PoliticaInsatisfacibles::Rechazar,
+        }
+    }
+
+    #[test]
+    fn el_swapping_no_desaloja_en_ciclo_a_procesos_que_no_avanzan() {
+        // Sólo entran dos de los tres procesos a la vez y el swapping es instantáneo: antes, cada uno
+        // se llevaba al swap apenas volvía y ninguno pasaba de su primera unidad de ejecución
+        let procesos = vec![
+            Proceso::new("A", 0, 5, 150),
+            Proceso::new("B", 0, 5, 150),
+            Proceso::new("C", 1, 5, 150),
+        ];
+        for swapping in [PoliticaSwapping::MasAntiguo, PoliticaSwapping::MasGrande, PoliticaSwapping::MenorTiempoRestante] {
+            for residencia_minima_swap in 0..=3 {
+                let (enviar, recibir) = mpsc::channel();
+                let procesos = procesos.clone();
+                let swapping = swapping.clone();
+                thread::spawn(move || {
+                    let configuracion = configuracion(swapping, residencia_minima_swap);
+                    let estado = Simulador::ejecutar_simulacion(
+                        &procesos,
+                        &configuracion,
+                        &mut Vec::new(),
+                        &mut Vec::new(),
+                        &mut Vec::new(),
+                    );
+                    enviar.send(matches!(estado, EstadoSimulacion::Completa)).unwrap();
+                });
+                let completa = recibir
+                    .recv_timeout(Duration::from_secs(10))
+                    .unwrap_or_else(|_| panic!("la simulación no termina (residencia mínima {})", residencia_minima_swap));
+                assert!(completa);
             }
         }
     }
-}
\ No newline at end of file
+}
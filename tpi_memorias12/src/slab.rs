@@ -0,0 +1,487 @@
+//slab.rs
+
+//Asignador slab sobre el sistema buddy: los procesos no piden un bloque de memoria sino objetos
+//del kernel de unos pocos tipos fijos. Cada tipo tiene su cache, formada por slabs (bloques buddy
+//de un mismo tamaño) divididos en ranuras para objetos de ese tipo. Los slabs de cada cache se
+//mantienen en listas de llenos, parciales y vacíos; los vacíos pueden devolverse al sistema buddy
+//cuando otra cache necesita memoria.
+
+use crate::buddy::MemoriaBuddy;
+use crate::memoria::{ModeloMemoria, Redimension, Ubicacion};
+use crate::particion::EstadoParticion;
+use crate::proceso::Proceso;
+
+/// Tipo de objeto del kernel que puede pedir un proceso
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipoObjeto {
+    Buffer,
+    Tarea,
+    Socket,
+    Inodo,
+}
+
+/// Tipos de objeto, del más grande al más chico
+pub const TIPOS_OBJETO: [TipoObjeto; 4] = [TipoObjeto::Buffer, TipoObjeto::Tarea, TipoObjeto::Socket, TipoObjeto::Inodo];
+
+/// Orden del slab más chico: en un slab tiene que entrar al menos un objeto de cada tipo
+pub const ORDEN_SLAB_MINIMO: u32 = 4;
+
+impl TipoObjeto {
+    /// Tamaño de un objeto de este tipo, en KB
+    pub fn tamanio(self) -> u32 {
+        match self {
+            TipoObjeto::Buffer => 16,
+            TipoObjeto::Tarea => 6,
+            TipoObjeto::Socket => 5,
+            TipoObjeto::Inodo => 3,
+        }
+    }
+
+    /// Nombre del tipo en tablas, mensajes y archivos de procesos
+    pub fn nombre(self) -> &'static str {
+        match self {
+            TipoObjeto::Buffer => "buffer",
+            TipoObjeto::Tarea => "tarea",
+            TipoObjeto::Socket => "socket",
+            TipoObjeto::Inodo => "inodo",
+        }
+    }
+
+    /// Tipo que corresponde a un nombre, sin distinguir mayúsculas
+    pub fn desde_nombre(nombre: &str) -> Option<TipoObjeto> {
+        TIPOS_OBJETO.iter().copied().find(|t| t.nombre().eq_ignore_ascii_case(nombre))
+    }
+}
+
+/// Bloque buddy dividido en ranuras para objetos de un mismo tipo
+struct Slab {
+    id: String,                     // Nombre con el que el slab figura en el sistema buddy
+    direccion: u32,
+    ranuras: Vec<Option<String>>,   // Proceso dueño del objeto de cada ranura (None si está libre)
+}
+
+impl Slab {
+    fn en_uso(&self) -> u32 {
+        self.ranuras.iter().filter(|r| r.is_some()).count() as u32
+    }
+
+    fn libres(&self) -> u32 {
+        self.ranuras.len() as u32 - self.en_uso()
+    }
+}
+
+/// Cache de objetos de un tipo, con sus slabs llenos, parciales y vacíos
+struct Cache {
+    tipo: TipoObjeto,
+    objetos_por_slab: u32,
+    sobrante_por_slab: u32, // KB al final de cada slab donde ya no entra otro objeto
+    llenos: Vec<Slab>,
+    parciales: Vec<Slab>,
+    vacios: Vec<Slab>,
+    pedidos: u64,           // Objetos entregados
+    slabs_creados: u32,
+    slabs_recuperados: u32,
+    slabs_acumulado: u64,   // Slabs de la cache por unidad de tiempo
+    en_uso_acumulado: u64,  // Objetos en uso por unidad de tiempo
+}
+
+impl Cache {
+    fn nueva(tipo: TipoObjeto, tamanio_slab: u32) -> Cache {
+        Cache {
+            tipo,
+            objetos_por_slab: tamanio_slab / tipo.tamanio(),
+            sobrante_por_slab: tamanio_slab % tipo.tamanio(),
+            llenos: Vec::new(),
+            parciales: Vec::new(),
+            vacios: Vec::new(),
+            pedidos: 0,
+            slabs_creados: 0,
+            slabs_recuperados: 0,
+            slabs_acumulado: 0,
+            en_uso_acumulado: 0,
+        }
+    }
+
+    fn slabs(&self) -> impl Iterator<Item = &Slab> {
+        self.llenos.iter().chain(self.parciales.iter()).chain(self.vacios.iter())
+    }
+
+    fn cantidad_slabs(&self) -> u32 {
+        (self.llenos.len() + self.parciales.len() + self.vacios.len()) as u32
+    }
+
+    fn en_uso(&self) -> u32 {
+        self.slabs().map(|s| s.en_uso()).sum()
+    }
+
+    /// Objetos que tiene el proceso en esta cache
+    fn objetos_de(&self, nombre_proceso: &str) -> u32 {
+        self.slabs()
+            .flat_map(|s| s.ranuras.iter())
+            .filter(|r| r.as_deref() == Some(nombre_proceso))
+            .count() as u32
+    }
+
+    /// Slabs nuevos que necesita la cache para entregar `cantidad` objetos más
+    fn slabs_faltantes(&self, cantidad: u32) -> u32 {
+        let libres: u32 = self.parciales.iter().chain(self.vacios.iter()).map(|s| s.libres()).sum();
+        cantidad.saturating_sub(libres).div_ceil(self.objetos_por_slab)
+    }
+
+    /// Slabs vacíos que le siguen sobrando a la cache después de entregar `cantidad` objetos más
+    fn vacios_sobrantes(&self, cantidad: u32) -> u32 {
+        let libres_parciales: u32 = self.parciales.iter().map(|s| s.libres()).sum();
+        let necesarios = cantidad.saturating_sub(libres_parciales).div_ceil(self.objetos_por_slab);
+        (self.vacios.len() as u32).saturating_sub(necesarios)
+    }
+
+    /// Entrega la primera ranura libre del primer slab parcial (o, si no hay, del primer vacío) y
+    /// devuelve la dirección del objeto
+    fn asignar_objeto(&mut self, nombre_proceso: &str) -> Option<u32> {
+        let slab = if self.parciales.is_empty() {
+            self.vacios.first_mut()?
+        } else {
+            &mut self.parciales[0]
+        };
+        let ranura = slab.ranuras.iter().position(|r| r.is_none())?;
+        slab.ranuras[ranura] = Some(nombre_proceso.to_string());
+        let direccion = slab.direccion + ranura as u32 * self.tipo.tamanio();
+        self.pedidos += 1;
+        self.reclasificar();
+        Some(direccion)
+    }
+
+    /// Libera hasta `cantidad` objetos del proceso, empezando por los slabs de mayor dirección
+    fn liberar_objetos(&mut self, nombre_proceso: &str, cantidad: u32) {
+        let mut restantes = cantidad;
+        for slab in self.llenos.iter_mut().chain(self.parciales.iter_mut()).rev() {
+            for ranura in slab.ranuras.iter_mut().rev() {
+                if restantes > 0 && ranura.as_deref() == Some(nombre_proceso) {
+                    *ranura = None;
+                    restantes -= 1;
+                }
+            }
+        }
+        self.reclasificar();
+    }
+
+    /// Vuelve a repartir los slabs entre las listas de llenos, parciales y vacíos, por dirección
+    fn reclasificar(&mut self) {
+        let mut slabs: Vec<Slab> = self
+            .llenos
+            .drain(..)
+            .chain(self.parciales.drain(..))
+            .chain(self.vacios.drain(..))
+            .collect();
+        slabs.sort_by_key(|s| s.direccion);
+        for slab in slabs {
+            if slab.libres() == 0 {
+                self.llenos.push(slab);
+            } else if slab.en_uso() == 0 {
+                self.vacios.push(slab);
+            } else {
+                self.parciales.push(slab);
+            }
+        }
+    }
+}
+
+pub struct MemoriaSlab {
+    buddy: MemoriaBuddy,      // Entrega y recibe los slabs, todos del mismo tamaño
+    orden_slab: u32,          // Cada slab mide 2^orden_slab KB
+    recuperar_vacios: bool,   // Si los slabs vacíos se devuelven al buddy cuando falta memoria
+    caches: Vec<Cache>,       // Una cache por tipo de objeto, en el orden de TIPOS_OBJETO
+    slabs_pedidos: u32,       // Para dar un nombre distinto a cada slab en el sistema buddy
+    mensajes: Vec<String>,
+}
+
+impl MemoriaSlab {
+    /// El sistema buddy tiene como bloque mínimo al slab, así que nunca divide por debajo de ese tamaño
+    pub fn nueva(tamanio_memoria: u32, orden_slab: u32, recuperar_vacios: bool) -> MemoriaSlab {
+        MemoriaSlab {
+            buddy: MemoriaBuddy::nueva(tamanio_memoria, orden_slab),
+            orden_slab,
+            recuperar_vacios,
+            caches: TIPOS_OBJETO.iter().map(|&tipo| Cache::nueva(tipo, 1 << orden_slab)).collect(),
+            slabs_pedidos: 0,
+            mensajes: Vec::new(),
+        }
+    }
+
+    fn tamanio_slab(&self) -> u32 {
+        1 << self.orden_slab
+    }
+
+    /// Objetos que pide el proceso, por cache
+    fn cantidades(proceso: &Proceso) -> Vec<u32> {
+        let objetos = proceso.get_objetos();
+        TIPOS_OBJETO
+            .iter()
+            .map(|&tipo| objetos.iter().filter(|o| o.tipo == tipo).map(|o| o.cantidad).sum())
+            .collect()
+    }
+
+    /// Dirección del primer objeto del proceso
+    fn direccion_de(&self, nombre_proceso: &str) -> Option<u32> {
+        self.caches
+            .iter()
+            .flat_map(|c| c.slabs().map(move |s| (c, s)))
+            .flat_map(|(c, s)| {
+                s.ranuras
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, r)| r.as_deref() == Some(nombre_proceso))
+                    .map(move |(ranura, _)| s.direccion + ranura as u32 * c.tipo.tamanio())
+            })
+            .min()
+    }
+
+    /// Pide al sistema buddy un slab nuevo para la cache. Siempre hay lugar si quedan `tamanio_slab` KB
+    /// libres, porque todos los bloques del buddy miden al menos un slab.
+    fn crear_slab(&mut self, cache: usize) {
+        self.slabs_pedidos += 1;
+        let id = format!("slab {}", self.slabs_pedidos);
+        let tamanio_slab = self.tamanio_slab();
+        let ubicacion = self
+            .buddy
+            .asignar(&Proceso::new(&id, 0, 1, tamanio_slab), 0)
+            .expect("el sistema buddy tenía lugar para el slab");
+        self.mensajes.extend(self.buddy.tomar_mensajes());
+
+        let cache = &mut self.caches[cache];
+        cache.slabs_creados += 1;
+        cache.vacios.push(Slab {
+            id,
+            direccion: ubicacion.direccion,
+            ranuras: vec![None; cache.objetos_por_slab as usize],
+        });
+        cache.reclasificar();
+        self.mensajes.push(format!(
+            "la cache de {} crea un slab de {} KB en la dirección {} ({} objetos de {} KB, {} KB sin usar)",
+            cache.tipo.nombre(),
+            tamanio_slab,
+            ubicacion.direccion,
+            cache.objetos_por_slab,
+            cache.tipo.tamanio(),
+            cache.sobrante_por_slab
+        ));
+    }
+
+    /// Devuelve al sistema buddy el último slab vacío de la cache
+    fn recuperar_slab(&mut self, cache: usize) {
+        let Some(slab) = self.caches[cache].vacios.pop() else {
+            return;
+        };
+        self.caches[cache].slabs_recuperados += 1;
+        self.mensajes.push(format!(
+            "se recupera el slab vacío de la cache de {} en la dirección {} y vuelve al sistema buddy",
+            self.caches[cache].tipo.nombre(),
+            slab.direccion
+        ));
+        self.buddy.liberar(&slab.id);
+        self.mensajes.extend(self.buddy.tomar_mensajes());
+    }
+
+    /// Entrega al proceso `cantidades` objetos más de cada tipo, o ninguno si no entran todos.
+    /// Primero se usan las ranuras libres de cada cache y después se crean slabs; si no alcanza la
+    /// memoria del buddy y está habilitado, se recuperan los slabs vacíos que las otras caches no usan.
+    fn reservar(&mut self, nombre_proceso: &str, cantidades: &[u32]) -> bool {
+        let faltantes: u32 = self
+            .caches
+            .iter()
+            .zip(cantidades)
+            .map(|(c, &cantidad)| c.slabs_faltantes(cantidad))
+            .sum();
+        let mut disponibles = self.buddy.memoria_libre() / self.tamanio_slab();
+        if faltantes > disponibles {
+            let sobrantes: u32 = self
+                .caches
+                .iter()
+                .zip(cantidades)
+                .map(|(c, &cantidad)| c.vacios_sobrantes(cantidad))
+                .sum();
+            if !self.recuperar_vacios || faltantes > disponibles + sobrantes {
+                return false;
+            }
+            for (cache, &cantidad) in cantidades.iter().enumerate() {
+                while disponibles < faltantes && self.caches[cache].vacios_sobrantes(cantidad) > 0 {
+                    self.recuperar_slab(cache);
+                    disponibles += 1;
+                }
+            }
+        }
+
+        for (cache, &cantidad) in cantidades.iter().enumerate() {
+            for _ in 0..cantidad {
+                if self.caches[cache].asignar_objeto(nombre_proceso).is_none() {
+                    self.crear_slab(cache);
+                    self.caches[cache].asignar_objeto(nombre_proceso);
+                }
+            }
+        }
+        true
+    }
+
+    /// Objetos en uso sobre el total de ranuras y sobrantes de los slabs, en KB
+    fn utilizacion(&self) -> (u64, u64) {
+        self.caches.iter().fold((0, 0), |(en_uso, en_slabs), c| {
+            (
+                en_uso + c.en_uso_acumulado * c.tipo.tamanio() as u64,
+                en_slabs + c.slabs_acumulado * self.tamanio_slab() as u64,
+            )
+        })
+    }
+}
+
+impl ModeloMemoria for MemoriaSlab {
+    fn asignar(&mut self, proceso: &Proceso, _tiempo_carga: u32) -> Option<Ubicacion> {
+        let cantidades = MemoriaSlab::cantidades(proceso);
+        if !self.reservar(proceso.get_nombre(), &cantidades) {
+            return None;
+        }
+        Some(Ubicacion {
+            direccion: self.direccion_de(proceso.get_nombre())?,
+            tamanio: cantidades.iter().zip(TIPOS_OBJETO.iter()).map(|(&c, t)| c * t.tamanio()).sum(),
+        })
+    }
+
+    /// Las ranuras sólo registran a qué proceso pertenece cada objeto, no si se está cargando o liberando
+    fn cambiar_estado(&mut self, _nombre_proceso: &str, _estado: EstadoParticion) {}
+
+    /// Los objetos nunca se mueven: si el proceso crece se le entregan los objetos que le faltan
+    /// (todos o ninguno), y si se achica devuelve los que le sobran.
+    fn redimensionar(&mut self, proceso: &Proceso) -> Redimension {
+        let actuales: Vec<u32> = self.caches.iter().map(|c| c.objetos_de(proceso.get_nombre())).collect();
+        if actuales.iter().all(|&c| c == 0) {
+            return Redimension::SinLugar;
+        }
+        let nuevas = MemoriaSlab::cantidades(proceso);
+        let agregar: Vec<u32> = nuevas.iter().zip(&actuales).map(|(n, a)| n.saturating_sub(*a)).collect();
+        if !self.reservar(proceso.get_nombre(), &agregar) {
+            return Redimension::SinLugar;
+        }
+        for (cache, (nueva, actual)) in nuevas.iter().zip(&actuales).enumerate() {
+            if actual > nueva {
+                self.caches[cache].liberar_objetos(proceso.get_nombre(), actual - nueva);
+            }
+        }
+        Redimension::EnLugar
+    }
+
+    /// Los slabs que quedan vacíos siguen en su cache hasta que otra los necesite
+    fn liberar(&mut self, nombre_proceso: &str) {
+        for cache in self.caches.iter_mut() {
+            cache.liberar_objetos(nombre_proceso, u32::MAX);
+        }
+    }
+
+    fn tamanio_total(&self) -> u32 {
+        self.buddy.tamanio_total()
+    }
+
+    /// Los objetos de cada tipo ocupan slabs enteros de su cache: lo que cuenta es cuántos
+    /// slabs necesita el proceso, no cuántos KB suman sus objetos
+    fn cabe_con_memoria_vacia(&self, proceso: &Proceso) -> Result<(), String> {
+        let necesarios: u32 = self
+            .caches
+            .iter()
+            .zip(MemoriaSlab::cantidades(proceso))
+            .map(|(c, cantidad)| cantidad.div_ceil(c.objetos_por_slab))
+            .sum();
+        let capacidad = self.tamanio_total() / self.tamanio_slab();
+        if necesarios <= capacidad {
+            Ok(())
+        } else {
+            Err(format!(
+                "sus objetos necesitan {} slabs de {} KB, pero en la memoria entran a lo sumo {}",
+                necesarios,
+                self.tamanio_slab(),
+                capacidad
+            ))
+        }
+    }
+
+    /// Memoria en slabs, con sus ranuras libres incluidas
+    fn memoria_ocupada(&self) -> u32 {
+        self.buddy.memoria_ocupada()
+    }
+
+    fn huecos(&self) -> Vec<u32> {
+        self.buddy.huecos()
+    }
+
+    fn avanzar_tiempo(&mut self, duracion: u64) {
+        for cache in self.caches.iter_mut() {
+            cache.slabs_acumulado += cache.cantidad_slabs() as u64 * duracion;
+            cache.en_uso_acumulado += cache.en_uso() as u64 * duracion;
+        }
+        self.buddy.avanzar_tiempo(duracion);
+    }
+
+    fn tomar_mensajes(&mut self) -> Vec<String> {
+        self.mensajes.extend(self.buddy.tomar_mensajes());
+        std::mem::take(&mut self.mensajes)
+    }
+
+    fn resumen(&self, tiempo_total: u64) -> Vec<String> {
+        let mut resumen = vec![format!(
+            "Slab: slabs de {} KB sobre el sistema buddy, recuperación de slabs vacíos {}. Slabs creados: {}. Slabs recuperados: {}.",
+            self.tamanio_slab(),
+            if self.recuperar_vacios { "habilitada" } else { "deshabilitada" },
+            self.caches.iter().map(|c| c.slabs_creados).sum::<u32>(),
+            self.caches.iter().map(|c| c.slabs_recuperados).sum::<u32>()
+        )];
+        resumen.extend(self.buddy.resumen(tiempo_total).into_iter().take(2));
+
+        // Promedios ponderados por tiempo sobre toda la simulación
+        let por_tiempo = |acumulado: u64| {
+            if tiempo_total > 0 {
+                acumulado as f64 / tiempo_total as f64
+            } else {
+                0.0
+            }
+        };
+        for cache in &self.caches {
+            let slabs_medios = por_tiempo(cache.slabs_acumulado);
+            let en_uso_medio = por_tiempo(cache.en_uso_acumulado);
+            let utilizacion = if cache.slabs_acumulado > 0 {
+                cache.en_uso_acumulado as f64 * 100.0 / (cache.slabs_acumulado * cache.objetos_por_slab as u64) as f64
+            } else {
+                0.0
+            };
+            // Desperdicio interno: lo que sobra al final de cada slab más las ranuras sin objeto
+            let sobrantes = slabs_medios * cache.sobrante_por_slab as f64;
+            let ranuras_libres = (slabs_medios * cache.objetos_por_slab as f64 - en_uso_medio) * cache.tipo.tamanio() as f64;
+            resumen.push(format!(
+                "Cache de {} ({} KB, {} objetos por slab): {} objetos pedidos, {} slabs creados y {} recuperados; al finalizar {} llenos, {} parciales y {} vacíos.",
+                cache.tipo.nombre(),
+                cache.tipo.tamanio(),
+                cache.objetos_por_slab,
+                cache.pedidos,
+                cache.slabs_creados,
+                cache.slabs_recuperados,
+                cache.llenos.len(),
+                cache.parciales.len(),
+                cache.vacios.len()
+            ));
+            resumen.push(format!(
+                "Cache de {}: {:.2} slabs en promedio, utilización media {:.2}%, desperdicio interno medio {:.2} KB ({:.2} KB sobrantes al final de los slabs y {:.2} KB en ranuras libres).",
+                cache.tipo.nombre(),
+                slabs_medios,
+                utilizacion,
+                sobrantes + ranuras_libres,
+                sobrantes,
+                ranuras_libres
+            ));
+        }
+
+        let (en_uso, en_slabs) = self.utilizacion();
+        resumen.push(format!(
+            "Utilización media de los slabs: {:.2}% ({:.2} KB de objetos en uso sobre {:.2} KB en slabs).",
+            if en_slabs > 0 { en_uso as f64 * 100.0 / en_slabs as f64 } else { 0.0 },
+            por_tiempo(en_uso),
+            por_tiempo(en_slabs)
+        ));
+        resumen
+    }
+}
@@ -0,0 +1,35 @@
+/// Política de swapping: decide qué proceso en ejecución se lleva al área de swap
+/// cuando un proceso que espera no entra en memoria.
+#[derive(Debug, Clone)]
+pub enum PoliticaSwapping {
+    Deshabilitado,
+    MasAntiguo,          // El que lleva más tiempo en memoria
+    MasGrande,           // El que ocupa más memoria
+    MenorTiempoRestante, // El que tiene menos tiempo de ejecución por delante
+}
+
+/// Proceso en ejecución que podría llevarse al área de swap
+pub struct Residente {
+    pub indice: usize,        // Índice del proceso dentro de la tanda
+    pub cargado_en: u64,      // Instante en que entró a memoria
+    pub memoria: u32,         // KB que ocupa
+    pub tiempo_restante: u64, // Tiempo de ejecución que le queda
+}
+
+impl PoliticaSwapping {
+    /// Ordena los residentes según la preferencia de la política: el primero es la víctima preferida.
+    /// El orden es estable, así que a igualdad se respeta el orden en que se cargaron.
+    pub fn ordenar_victimas(&self, residentes: &mut [Residente]) {
+        match self {
+            PoliticaSwapping::Deshabilitado => {}
+            PoliticaSwapping::MasAntiguo => residentes.sort_by_key(|r| r.cargado_en),
+            PoliticaSwapping::MasGrande => residentes.sort_by_key(|r| std::cmp::Reverse(r.memoria)),
+            PoliticaSwapping::MenorTiempoRestante => residentes.sort_by_key(|r| r.tiempo_restante),
+        }
+    }
+}
+
+/// Tiempo que lleva transferir `memoria` KB entre la memoria y el área de swap
+pub fn tiempo_transferencia(memoria: u32, tasa_transferencia: u32) -> u64 {
+    memoria.div_ceil(tasa_transferencia) as u64
+}
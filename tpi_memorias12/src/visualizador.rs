@@ -57,12 +57,10 @@ fn enlistar_simulaciones() -> Vec<String> {
             }
         };
 
-        for entry in entries {
-            if let Ok(entry) = entry {
-                let file_name = entry.file_name();
-                let file_name = file_name.to_string_lossy().to_string();
-                archivos_simulaciones.push(file_name);
-            }
+        for entry in entries.flatten() {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy().to_string();
+            archivos_simulaciones.push(file_name);
         }
     } else {
         println!("La carpeta 'files' no existe.");
@@ -77,15 +75,13 @@ pub fn mostrar_por_pantalla(nombre_archivo: &str) {
     let path = Path::new(&path);
 
     // Intentamos abrir el archivo de simulación
-    if let Ok(file) = File::open(&path) {
+    if let Ok(file) = File::open(path) {
         let reader = io::BufReader::new(file);
 
         println!("\nContenido de la simulación '{}':\n", nombre_archivo);
         // Mostramos el contenido del archivo línea por línea
-        for line in reader.lines() {
-            if let Ok(line) = line {
-                println!("{}", line);
-            }
+        for line in reader.lines().map_while(Result::ok) {
+            println!("{}", line);
         }
     } else {
         println!("No se pudo abrir el archivo de simulación.");